@@ -0,0 +1,11 @@
+use aoclib::AocError;
+
+use aoc2022_day01::part1::process;
+
+fn main() -> Result<(), AocError> {
+    let input = include_str!("../../input.txt");
+    let output = process(input)?;
+    println!("=== Part 1 Result ===");
+    println!("{}", output);
+    Ok(())
+}
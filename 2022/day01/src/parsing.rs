@@ -0,0 +1,29 @@
+use aoclib::AocError;
+use nom::{
+    character::complete::{line_ending, u32 as u32_parser},
+    multi::{many1, separated_list1},
+    sequence::terminated,
+    IResult,
+};
+
+fn parse_elf(input: &str) -> IResult<&str, Vec<u32>> {
+    many1(terminated(u32_parser, line_ending))(input)
+}
+
+/// Parses the calories of the food items each elf carries
+pub fn parse_calories(input: &str) -> Result<Vec<Vec<u32>>, AocError> {
+    let (rest, elves) = separated_list1(line_ending, parse_elf)(input)?;
+    if rest.is_empty() {
+        Ok(elves)
+    } else {
+        Err(AocError::ParseError(format!(
+            "Parsing calories left rest: {}",
+            rest
+        )))
+    }
+}
+
+pub fn calories_per_elf(input: &str) -> Result<Vec<u32>, AocError> {
+    let elves = parse_calories(input)?;
+    Ok(elves.iter().map(|items| items.iter().sum()).collect())
+}
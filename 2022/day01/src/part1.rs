@@ -0,0 +1,8 @@
+use aoclib::AocError;
+
+use crate::parsing::calories_per_elf;
+
+pub fn process(input: &'static str) -> Result<u32, AocError> {
+    let calories = calories_per_elf(input)?;
+    Ok(calories.into_iter().max().unwrap_or(0))
+}
@@ -0,0 +1,11 @@
+use aoclib::AocError;
+
+use crate::parsing::calories_per_elf;
+
+const TOP_ELVES: usize = 3;
+
+pub fn process(input: &'static str) -> Result<u32, AocError> {
+    let mut calories = calories_per_elf(input)?;
+    calories.sort_unstable_by(|a, b| b.cmp(a));
+    Ok(calories.into_iter().take(TOP_ELVES).sum())
+}
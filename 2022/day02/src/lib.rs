@@ -0,0 +1,57 @@
+pub mod parsing;
+pub mod part1;
+pub mod part2;
+
+#[cfg(test)]
+mod tests {
+    use crate::parsing::{parse_strategy_guide, Outcome, Shape};
+    use crate::part1;
+    use crate::part2;
+    use aoclib::AocError;
+
+    #[test]
+    fn test_part1() -> Result<(), AocError> {
+        let input = include_str!("../test_input1.txt");
+        if input.is_empty() {
+            return Err(AocError::ParseError(
+                "Input file for test 1 is empty".to_string(),
+            ));
+        }
+        let output = part1::process(input)?;
+        let expected_output = 15;
+        assert_eq!(expected_output, output);
+        Ok(())
+    }
+
+    #[test]
+    fn test_part2() -> Result<(), AocError> {
+        let input = include_str!("../test_input2.txt");
+        if input.is_empty() {
+            return Err(AocError::ParseError(
+                "Input file for test 2 is empty".to_string(),
+            ));
+        }
+        let output = part2::process(input)?;
+        let expected_output = 12;
+        assert_eq!(expected_output, output);
+        Ok(())
+    }
+
+    #[test]
+    fn test_outcomes() {
+        let shapes = [Shape::Rock, Shape::Paper, Shape::Scissors];
+        shapes.iter().for_each(|shape| {
+            assert_eq!(Outcome::Draw, shape.outcome_against(*shape));
+            assert_eq!(Outcome::Win, shape.outcome_against(shape.beats()));
+            assert_eq!(Outcome::Loss, shape.outcome_against(shape.beaten_by()));
+        });
+    }
+
+    #[test]
+    fn test_invalid_round() {
+        assert!(matches!(
+            parse_strategy_guide("A Y\nD X\n"),
+            Err(AocError::ParseError(_))
+        ));
+    }
+}
@@ -0,0 +1,110 @@
+use aoclib::AocError;
+use nom::{
+    branch::alt,
+    character::complete::{char as char_parser, line_ending},
+    combinator::value,
+    multi::many1,
+    sequence::{separated_pair, terminated},
+    IResult,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Shape {
+    Rock,
+    Paper,
+    Scissors,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    Loss,
+    Draw,
+    Win,
+}
+
+/// Second column of the strategy guide, which means something different in each part
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Instruction {
+    X,
+    Y,
+    Z,
+}
+
+impl Shape {
+    /// Shape that beats this one
+    pub fn beaten_by(&self) -> Shape {
+        match self {
+            Shape::Rock => Shape::Paper,
+            Shape::Paper => Shape::Scissors,
+            Shape::Scissors => Shape::Rock,
+        }
+    }
+
+    /// Shape that this one beats
+    pub fn beats(&self) -> Shape {
+        self.beaten_by().beaten_by()
+    }
+
+    pub fn outcome_against(&self, opponent: Shape) -> Outcome {
+        if *self == opponent {
+            Outcome::Draw
+        } else if self.beats() == opponent {
+            Outcome::Win
+        } else {
+            Outcome::Loss
+        }
+    }
+
+    fn score(&self) -> u32 {
+        match self {
+            Shape::Rock => 1,
+            Shape::Paper => 2,
+            Shape::Scissors => 3,
+        }
+    }
+}
+
+impl Outcome {
+    fn score(&self) -> u32 {
+        match self {
+            Outcome::Loss => 0,
+            Outcome::Draw => 3,
+            Outcome::Win => 6,
+        }
+    }
+}
+
+pub fn round_score(opponent: Shape, me: Shape) -> u32 {
+    me.score() + me.outcome_against(opponent).score()
+}
+
+fn parse_shape(input: &str) -> IResult<&str, Shape> {
+    alt((
+        value(Shape::Rock, char_parser('A')),
+        value(Shape::Paper, char_parser('B')),
+        value(Shape::Scissors, char_parser('C')),
+    ))(input)
+}
+
+fn parse_instruction(input: &str) -> IResult<&str, Instruction> {
+    alt((
+        value(Instruction::X, char_parser('X')),
+        value(Instruction::Y, char_parser('Y')),
+        value(Instruction::Z, char_parser('Z')),
+    ))(input)
+}
+
+pub fn parse_strategy_guide(input: &str) -> Result<Vec<(Shape, Instruction)>, AocError> {
+    let (rest, rounds) = many1(terminated(
+        separated_pair(parse_shape, char_parser(' '), parse_instruction),
+        line_ending,
+    ))(input)?;
+    if rest.is_empty() {
+        Ok(rounds)
+    } else {
+        Err(AocError::ParseError(format!(
+            "Parsing strategy guide left rest: {}",
+            rest
+        )))
+    }
+}
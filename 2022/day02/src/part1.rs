@@ -0,0 +1,19 @@
+use aoclib::AocError;
+
+use crate::parsing::{parse_strategy_guide, round_score, Instruction, Shape};
+
+fn my_shape(instruction: Instruction) -> Shape {
+    match instruction {
+        Instruction::X => Shape::Rock,
+        Instruction::Y => Shape::Paper,
+        Instruction::Z => Shape::Scissors,
+    }
+}
+
+pub fn process(input: &'static str) -> Result<u32, AocError> {
+    let rounds = parse_strategy_guide(input)?;
+    Ok(rounds
+        .into_iter()
+        .map(|(opponent, instruction)| round_score(opponent, my_shape(instruction)))
+        .sum())
+}
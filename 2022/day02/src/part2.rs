@@ -0,0 +1,30 @@
+use aoclib::AocError;
+
+use crate::parsing::{parse_strategy_guide, round_score, Instruction, Outcome, Shape};
+
+fn wanted_outcome(instruction: Instruction) -> Outcome {
+    match instruction {
+        Instruction::X => Outcome::Loss,
+        Instruction::Y => Outcome::Draw,
+        Instruction::Z => Outcome::Win,
+    }
+}
+
+fn shape_for_outcome(opponent: Shape, outcome: Outcome) -> Shape {
+    match outcome {
+        Outcome::Loss => opponent.beats(),
+        Outcome::Draw => opponent,
+        Outcome::Win => opponent.beaten_by(),
+    }
+}
+
+pub fn process(input: &'static str) -> Result<u32, AocError> {
+    let rounds = parse_strategy_guide(input)?;
+    Ok(rounds
+        .into_iter()
+        .map(|(opponent, instruction)| {
+            let me = shape_for_outcome(opponent, wanted_outcome(instruction));
+            round_score(opponent, me)
+        })
+        .sum())
+}
@@ -0,0 +1,63 @@
+pub mod parsing;
+pub mod part1;
+pub mod part2;
+
+#[cfg(test)]
+mod tests {
+    use crate::parsing::{common_item, priority};
+    use crate::part1;
+    use crate::part2;
+    use aoclib::AocError;
+
+    #[test]
+    fn test_part1() -> Result<(), AocError> {
+        let input = include_str!("../test_input1.txt");
+        if input.is_empty() {
+            return Err(AocError::ParseError(
+                "Input file for test 1 is empty".to_string(),
+            ));
+        }
+        let output = part1::process(input)?;
+        let expected_output = 157;
+        assert_eq!(expected_output, output);
+        Ok(())
+    }
+
+    #[test]
+    fn test_part2() -> Result<(), AocError> {
+        let input = include_str!("../test_input2.txt");
+        if input.is_empty() {
+            return Err(AocError::ParseError(
+                "Input file for test 2 is empty".to_string(),
+            ));
+        }
+        let output = part2::process(input)?;
+        let expected_output = 70;
+        assert_eq!(expected_output, output);
+        Ok(())
+    }
+
+    #[test]
+    fn test_priority() -> Result<(), AocError> {
+        assert_eq!(1, priority('a')?);
+        assert_eq!(52, priority('Z')?);
+        assert!(matches!(priority('1'), Err(AocError::ParseError(_))));
+        Ok(())
+    }
+
+    #[test]
+    fn test_invalid_rucksacks() {
+        assert!(matches!(
+            common_item(&["abc", "def"]),
+            Err(AocError::LogicError(_))
+        ));
+        assert!(matches!(
+            part1::process("abcda\n"),
+            Err(AocError::ParseError(_))
+        ));
+        assert!(matches!(
+            part2::process("ab\nba\n"),
+            Err(AocError::LogicError(_))
+        ));
+    }
+}
@@ -0,0 +1,54 @@
+use std::collections::HashSet;
+
+use aoclib::AocError;
+use nom::{
+    character::complete::{alpha1, line_ending},
+    multi::many1,
+    sequence::terminated,
+    IResult,
+};
+
+fn parse_rucksack(input: &str) -> IResult<&str, &str> {
+    terminated(alpha1, line_ending)(input)
+}
+
+pub fn parse_rucksacks(input: &str) -> Result<Vec<&str>, AocError> {
+    let (rest, rucksacks) = many1(parse_rucksack)(input)?;
+    if rest.is_empty() {
+        Ok(rucksacks)
+    } else {
+        Err(AocError::ParseError(format!(
+            "Parsing rucksacks left rest: {}",
+            rest
+        )))
+    }
+}
+
+/// Priority of an item type, `a` to `z` are 1 to 26 and `A` to `Z` are 27 to 52
+pub fn priority(item: char) -> Result<u32, AocError> {
+    match item {
+        'a'..='z' => Ok(item as u32 - 'a' as u32 + 1),
+        'A'..='Z' => Ok(item as u32 - 'A' as u32 + 27),
+        _ => Err(AocError::ParseError(format!("Invalid item type: {}", item))),
+    }
+}
+
+/// Returns the only item type all the item lists have in common
+pub fn common_item(item_lists: &[&str]) -> Result<char, AocError> {
+    let common = item_lists
+        .iter()
+        .map(|items| items.chars().collect::<HashSet<_>>())
+        .reduce(|common, items| &common & &items)
+        .unwrap_or_default();
+    let mut common: Vec<_> = common.into_iter().collect();
+    match common[..] {
+        [item] => Ok(item),
+        _ => {
+            common.sort();
+            Err(AocError::LogicError(format!(
+                "Expected exactly one common item type in {:?}, found {:?}",
+                item_lists, common
+            )))
+        }
+    }
+}
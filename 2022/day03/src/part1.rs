@@ -0,0 +1,17 @@
+use aoclib::AocError;
+
+use crate::parsing::{common_item, parse_rucksacks, priority};
+
+pub fn process(input: &'static str) -> Result<u32, AocError> {
+    let rucksacks = parse_rucksacks(input)?;
+    rucksacks.into_iter().try_fold(0, |sum, rucksack| {
+        if rucksack.len() % 2 != 0 {
+            return Err(AocError::ParseError(format!(
+                "Rucksack {} cannot be split into two equal compartments",
+                rucksack
+            )));
+        }
+        let (first, second) = rucksack.split_at(rucksack.len() / 2);
+        Ok(sum + priority(common_item(&[first, second])?)?)
+    })
+}
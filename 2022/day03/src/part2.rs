@@ -0,0 +1,19 @@
+use aoclib::AocError;
+
+use crate::parsing::{common_item, parse_rucksacks, priority};
+
+const GROUP_SIZE: usize = 3;
+
+pub fn process(input: &'static str) -> Result<u32, AocError> {
+    let rucksacks = parse_rucksacks(input)?;
+    if rucksacks.len() % GROUP_SIZE != 0 {
+        return Err(AocError::LogicError(format!(
+            "{} rucksacks cannot be split into groups of {} elves",
+            rucksacks.len(),
+            GROUP_SIZE
+        )));
+    }
+    rucksacks
+        .chunks(GROUP_SIZE)
+        .try_fold(0, |sum, group| Ok(sum + priority(common_item(group)?)?))
+}
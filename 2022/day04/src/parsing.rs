@@ -0,0 +1,32 @@
+use std::ops::RangeInclusive;
+
+use aoclib::AocError;
+use nom::{
+    character::complete::{char as char_parser, line_ending, u32 as u32_parser},
+    multi::many1,
+    sequence::{separated_pair, terminated},
+    IResult,
+};
+
+pub type Sections = RangeInclusive<u32>;
+
+fn parse_sections(input: &str) -> IResult<&str, Sections> {
+    let (input, (start, end)) = separated_pair(u32_parser, char_parser('-'), u32_parser)(input)?;
+    Ok((input, start..=end))
+}
+
+/// Parses the section assignments of each pair of elves
+pub fn parse_assignments(input: &str) -> Result<Vec<(Sections, Sections)>, AocError> {
+    let (rest, pairs) = many1(terminated(
+        separated_pair(parse_sections, char_parser(','), parse_sections),
+        line_ending,
+    ))(input)?;
+    if rest.is_empty() {
+        Ok(pairs)
+    } else {
+        Err(AocError::ParseError(format!(
+            "Parsing section assignments left rest: {}",
+            rest
+        )))
+    }
+}
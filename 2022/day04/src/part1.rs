@@ -0,0 +1,15 @@
+use aoclib::AocError;
+
+use crate::parsing::{parse_assignments, Sections};
+
+pub fn fully_contains(outer: &Sections, inner: &Sections) -> bool {
+    outer.start() <= inner.start() && inner.end() <= outer.end()
+}
+
+pub fn process(input: &'static str) -> Result<usize, AocError> {
+    let pairs = parse_assignments(input)?;
+    Ok(pairs
+        .iter()
+        .filter(|(first, second)| fully_contains(first, second) || fully_contains(second, first))
+        .count())
+}
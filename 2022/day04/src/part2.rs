@@ -0,0 +1,15 @@
+use aoclib::AocError;
+
+use crate::parsing::{parse_assignments, Sections};
+
+pub fn overlaps(first: &Sections, second: &Sections) -> bool {
+    first.start() <= second.end() && second.start() <= first.end()
+}
+
+pub fn process(input: &'static str) -> Result<usize, AocError> {
+    let pairs = parse_assignments(input)?;
+    Ok(pairs
+        .iter()
+        .filter(|(first, second)| overlaps(first, second))
+        .count())
+}
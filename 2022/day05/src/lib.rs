@@ -0,0 +1,90 @@
+pub mod parsing;
+pub mod part1;
+pub mod part2;
+pub mod stacks;
+
+#[cfg(test)]
+mod tests {
+    use crate::parsing::parse_procedure;
+    use crate::part1;
+    use crate::part2;
+    use crate::stacks::Move;
+    use aoclib::AocError;
+
+    #[test]
+    fn test_part1() -> Result<(), AocError> {
+        let input = include_str!("../test_input1.txt");
+        if input.is_empty() {
+            return Err(AocError::ParseError(
+                "Input file for test 1 is empty".to_string(),
+            ));
+        }
+        let output = part1::process(input)?;
+        let expected_output = "CMZ";
+        assert_eq!(expected_output, output);
+        Ok(())
+    }
+
+    #[test]
+    fn test_part2() -> Result<(), AocError> {
+        let input = include_str!("../test_input2.txt");
+        if input.is_empty() {
+            return Err(AocError::ParseError(
+                "Input file for test 2 is empty".to_string(),
+            ));
+        }
+        let output = part2::process(input)?;
+        let expected_output = "MCD";
+        assert_eq!(expected_output, output);
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_drawing() -> Result<(), AocError> {
+        let input = include_str!("../test_input1.txt");
+        let (stacks, moves) = parse_procedure(input)?;
+        assert_eq!(
+            vec![vec!['Z', 'N'], vec!['M', 'C', 'D'], vec!['P']],
+            stacks.stacks
+        );
+        assert_eq!(
+            Move {
+                count: 3,
+                from: 1,
+                to: 3
+            },
+            moves[1]
+        );
+        // same drawing without the trailing blanks
+        let stripped: String = input
+            .lines()
+            .map(|line| format!("{}\n", line.trim_end()))
+            .collect();
+        assert_eq!(stacks, parse_procedure(&stripped)?.0);
+        Ok(())
+    }
+
+    #[test]
+    fn test_invalid_procedures() {
+        let floating = "[A]    \n    [B]\n 1   2 \n\nmove 1 from 1 to 2\n";
+        assert!(matches!(
+            parse_procedure(floating),
+            Err(AocError::ParseError(_))
+        ));
+        let misnumbered = "[A] [B]\n 1   3 \n\nmove 1 from 1 to 2\n";
+        assert!(matches!(
+            parse_procedure(misnumbered),
+            Err(AocError::ParseError(_))
+        ));
+        let too_many = "[A] [B]\n 1   2 \n\nmove 2 from 1 to 2\n";
+        assert!(matches!(
+            part1::process(too_many),
+            Err(AocError::LogicError(_))
+        ));
+        let unknown_stack = "[A] [B]\n 1   2 \n\nmove 1 from 1 to 3\n";
+        assert!(matches!(
+            part2::process(unknown_stack),
+            Err(AocError::LogicError(_))
+        ));
+    }
+}
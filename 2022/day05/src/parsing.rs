@@ -0,0 +1,109 @@
+use aoclib::AocError;
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{
+        char as char_parser, line_ending, satisfy, space0, space1, u32 as u32_parser,
+    },
+    combinator::{map, value},
+    multi::{many1, separated_list1},
+    sequence::{delimited, preceded, terminated, tuple},
+    IResult,
+};
+
+use crate::stacks::{Move, Stacks};
+
+/// A cell of the drawing is either a crate like `[A]` or three blanks
+fn parse_cell(input: &str) -> IResult<&str, Option<char>> {
+    alt((
+        delimited(
+            char_parser('['),
+            map(satisfy(|c| c.is_ascii_uppercase()), Some),
+            char_parser(']'),
+        ),
+        value(None, tag("   ")),
+    ))(input)
+}
+
+fn parse_crate_row(input: &str) -> IResult<&str, Vec<Option<char>>> {
+    // trailing blanks are optional as editors like to strip them
+    terminated(
+        separated_list1(char_parser(' '), parse_cell),
+        tuple((space0, line_ending)),
+    )(input)
+}
+
+fn parse_stack_numbers(input: &str) -> IResult<&str, Vec<u32>> {
+    delimited(
+        space0,
+        separated_list1(space1, u32_parser),
+        tuple((space0, line_ending)),
+    )(input)
+}
+
+fn parse_move(input: &str) -> IResult<&str, Move> {
+    let (input, (count, from, to)) = terminated(
+        tuple((
+            preceded(tag("move "), u32_parser),
+            preceded(tag(" from "), u32_parser),
+            preceded(tag(" to "), u32_parser),
+        )),
+        line_ending,
+    )(input)?;
+    let crate_move = Move {
+        count: count as usize,
+        from: from as usize,
+        to: to as usize,
+    };
+    Ok((input, crate_move))
+}
+
+/// Turns the rows of the drawing into stacks, the numbers below have to count up from 1
+fn build_stacks(rows: Vec<Vec<Option<char>>>, numbers: Vec<u32>) -> Result<Stacks, AocError> {
+    if !numbers.iter().copied().eq(1..=numbers.len() as u32) {
+        return Err(AocError::ParseError(format!(
+            "Stacks are not numbered from 1 to {}: {:?}",
+            numbers.len(),
+            numbers
+        )));
+    }
+    let mut stacks: Vec<Vec<char>> = vec![Vec::new(); numbers.len()];
+    rows.iter().rev().enumerate().try_for_each(|(level, row)| {
+        if row.len() > numbers.len() {
+            return Err(AocError::ParseError(format!(
+                "Row with {} cells is wider than the {} stacks",
+                row.len(),
+                numbers.len()
+            )));
+        }
+        row.iter().zip(stacks.iter_mut().enumerate()).try_for_each(
+            |(cell, (idx, stack))| match cell {
+                Some(_) if stack.len() != level => Err(AocError::ParseError(format!(
+                    "Crate floats above a gap in stack {}",
+                    idx + 1
+                ))),
+                Some(crate_label) => {
+                    stack.push(*crate_label);
+                    Ok(())
+                }
+                None => Ok(()),
+            },
+        )
+    })?;
+    Ok(Stacks { stacks })
+}
+
+/// Parses the drawing of the stacks and the rearrangement procedure below it
+pub fn parse_procedure(input: &str) -> Result<(Stacks, Vec<Move>), AocError> {
+    let (input, rows) = many1(parse_crate_row)(input)?;
+    let (input, numbers) = parse_stack_numbers(input)?;
+    let (input, _) = line_ending(input)?;
+    let (rest, moves) = many1(parse_move)(input)?;
+    if !rest.is_empty() {
+        return Err(AocError::ParseError(format!(
+            "Parsing rearrangement procedure left rest: {}",
+            rest
+        )));
+    }
+    Ok((build_stacks(rows, numbers)?, moves))
+}
@@ -0,0 +1,7 @@
+use aoclib::AocError;
+
+use crate::stacks::{rearrange, CrateMover};
+
+pub fn process(input: &'static str) -> Result<String, AocError> {
+    rearrange(input, CrateMover::Model9000)
+}
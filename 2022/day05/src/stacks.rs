@@ -0,0 +1,79 @@
+use aoclib::AocError;
+
+/// Stacks of crates, each listed from the bottom to the top
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Stacks {
+    pub stacks: Vec<Vec<char>>,
+}
+
+/// Moves `count` crates from the stack numbered `from` to the one numbered `to`, numbers start
+/// at 1 like in the drawing
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Move {
+    pub count: usize,
+    pub from: usize,
+    pub to: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CrateMover {
+    /// Moves crates one at a time, reversing their order
+    Model9000,
+    /// Moves all crates at once, keeping their order
+    Model9001,
+}
+
+impl Stacks {
+    fn stack_index(&self, number: usize) -> Result<usize, AocError> {
+        if (1..=self.stacks.len()).contains(&number) {
+            Ok(number - 1)
+        } else {
+            Err(AocError::LogicError(format!(
+                "There is no stack {}, only stacks 1 to {}",
+                number,
+                self.stacks.len()
+            )))
+        }
+    }
+
+    pub fn apply(&mut self, crate_move: &Move, crane: CrateMover) -> Result<(), AocError> {
+        let from = self.stack_index(crate_move.from)?;
+        let to = self.stack_index(crate_move.to)?;
+        let height = self.stacks[from].len();
+        if crate_move.count > height {
+            return Err(AocError::LogicError(format!(
+                "Cannot move {} crates from stack {} holding {}",
+                crate_move.count, crate_move.from, height
+            )));
+        }
+        let mut lifted = self.stacks[from].split_off(height - crate_move.count);
+        if crane == CrateMover::Model9000 {
+            lifted.reverse();
+        }
+        self.stacks[to].extend(lifted);
+        Ok(())
+    }
+
+    /// Returns the crates on top of every stack
+    pub fn top_crates(&self) -> Result<String, AocError> {
+        self.stacks
+            .iter()
+            .enumerate()
+            .map(|(idx, stack)| {
+                stack
+                    .last()
+                    .copied()
+                    .ok_or(AocError::LogicError(format!("Stack {} is empty", idx + 1)))
+            })
+            .collect()
+    }
+}
+
+/// Rearranges the stacks with the crane and returns the crates on top afterwards
+pub fn rearrange(input: &str, crane: CrateMover) -> Result<String, AocError> {
+    let (mut stacks, moves) = crate::parsing::parse_procedure(input)?;
+    moves
+        .iter()
+        .try_for_each(|crate_move| stacks.apply(crate_move, crane))?;
+    stacks.top_crates()
+}
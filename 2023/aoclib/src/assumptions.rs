@@ -0,0 +1,94 @@
+use std::fmt;
+
+use crate::AocError;
+
+type Check<T> = Box<dyn Fn(&T) -> Result<(), String>>;
+
+/// A named property of the puzzle input that a solution relies on but the puzzle text does not
+/// guarantee
+pub struct Assumption<T: ?Sized> {
+    pub name: &'static str,
+    check: Check<T>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AssumptionViolation {
+    pub assumption: &'static str,
+    pub location: String,
+}
+
+impl fmt::Display for AssumptionViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "'{}' violated at {}", self.assumption, self.location)
+    }
+}
+
+/// Collection of assumptions a day declares about its (parsed) input
+///
+/// All assumptions are evaluated before solving so that an unsupported input is reported with
+/// every violated assumption instead of a panic or a wrong answer
+pub struct Assumptions<T: ?Sized> {
+    assumptions: Vec<Assumption<T>>,
+}
+
+impl<T: ?Sized> Assumptions<T> {
+    pub fn new() -> Self {
+        Self {
+            assumptions: Vec::new(),
+        }
+    }
+
+    /// Adds a named check which returns where the input violates the assumption on failure
+    pub fn assume(
+        mut self,
+        name: &'static str,
+        check: impl Fn(&T) -> Result<(), String> + 'static,
+    ) -> Self {
+        self.assumptions.push(Assumption {
+            name,
+            check: Box::new(check),
+        });
+        self
+    }
+
+    pub fn names(&self) -> impl Iterator<Item = &'static str> + '_ {
+        self.assumptions.iter().map(|assumption| assumption.name)
+    }
+
+    pub fn violations(&self, input: &T) -> Vec<AssumptionViolation> {
+        self.assumptions
+            .iter()
+            .filter_map(|assumption| {
+                (assumption.check)(input)
+                    .err()
+                    .map(|location| AssumptionViolation {
+                        assumption: assumption.name,
+                        location,
+                    })
+            })
+            .collect()
+    }
+
+    pub fn check(&self, input: &T) -> Result<(), AocError> {
+        let violations = self.violations(input);
+        if violations.is_empty() {
+            Ok(())
+        } else {
+            Err(AocError::UnsupportedInput(violations))
+        }
+    }
+}
+
+impl<T: ?Sized> Default for Assumptions<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+pub(crate) fn format_violations(violations: &[AssumptionViolation]) -> String {
+    violations
+        .iter()
+        .map(|violation| violation.to_string())
+        .collect::<Vec<_>>()
+        .join("; ")
+}
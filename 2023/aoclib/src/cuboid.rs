@@ -0,0 +1,62 @@
+use crate::point3::Point3;
+
+/// Axis aligned box of integer points, both corners are included
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Cuboid {
+    pub min: Point3,
+    pub max: Point3,
+}
+
+impl Cuboid {
+    /// Creates the box spanned by two opposite corners given in any order
+    pub fn new(corner: Point3, opposite_corner: Point3) -> Self {
+        Self {
+            min: corner.componentwise_min(&opposite_corner),
+            max: corner.componentwise_max(&opposite_corner),
+        }
+    }
+
+    pub fn size(&self) -> Point3 {
+        self.max - self.min + Point3::new(1, 1, 1)
+    }
+
+    pub fn volume(&self) -> i64 {
+        let size = self.size();
+        size.x * size.y * size.z
+    }
+
+    pub fn contains(&self, point: &Point3) -> bool {
+        (self.min.x..=self.max.x).contains(&point.x)
+            && (self.min.y..=self.max.y).contains(&point.y)
+            && (self.min.z..=self.max.z).contains(&point.z)
+    }
+
+    pub fn overlaps(&self, other: &Self) -> bool {
+        self.overlaps_xy(other) && self.min.z <= other.max.z && other.min.z <= self.max.z
+    }
+
+    /// Checks whether the boxes overlap when looking at them from above
+    pub fn overlaps_xy(&self, other: &Self) -> bool {
+        self.min.x <= other.max.x
+            && other.min.x <= self.max.x
+            && self.min.y <= other.max.y
+            && other.min.y <= self.max.y
+    }
+
+    pub fn translated(&self, offset: Point3) -> Self {
+        Self {
+            min: self.min + offset,
+            max: self.max + offset,
+        }
+    }
+
+    /// Returns all `(x, y)` columns the box occupies when looking at it from above
+    pub fn footprint(&self) -> impl Iterator<Item = (i64, i64)> + '_ {
+        (self.min.x..=self.max.x).flat_map(move |x| (self.min.y..=self.max.y).map(move |y| (x, y)))
+    }
+
+    pub fn points(&self) -> impl Iterator<Item = Point3> + '_ {
+        self.footprint()
+            .flat_map(move |(x, y)| (self.min.z..=self.max.z).map(move |z| Point3::new(x, y, z)))
+    }
+}
@@ -0,0 +1,59 @@
+use std::fmt;
+
+/// Directed graph in the DOT language of Graphviz, identifiers and attribute values are quoted
+/// so that any label can be used
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Dot {
+    name: String,
+    statements: Vec<String>,
+}
+
+fn quote(id: &str) -> String {
+    format!("\"{}\"", id.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+fn attribute_list(attributes: &[(&str, &str)]) -> String {
+    if attributes.is_empty() {
+        return String::new();
+    }
+    let attributes: Vec<_> = attributes
+        .iter()
+        .map(|(key, value)| format!("{}={}", key, quote(value)))
+        .collect();
+    format!(" [{}]", attributes.join(", "))
+}
+
+impl Dot {
+    pub fn digraph(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            statements: Vec::new(),
+        }
+    }
+
+    pub fn node(&mut self, id: &str, attributes: &[(&str, &str)]) -> &mut Self {
+        self.statements
+            .push(format!("{}{}", quote(id), attribute_list(attributes)));
+        self
+    }
+
+    pub fn edge(&mut self, from: &str, to: &str, attributes: &[(&str, &str)]) -> &mut Self {
+        self.statements.push(format!(
+            "{} -> {}{}",
+            quote(from),
+            quote(to),
+            attribute_list(attributes)
+        ));
+        self
+    }
+}
+
+impl fmt::Display for Dot {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "digraph {} {{", quote(&self.name))?;
+        self.statements
+            .iter()
+            .try_for_each(|statement| writeln!(f, "    {};", statement))?;
+        writeln!(f, "}}")
+    }
+}
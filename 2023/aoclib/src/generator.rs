@@ -0,0 +1,137 @@
+use crate::AocError;
+
+/// Small SplitMix64 random number generator so that generated inputs only depend on their seed
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+
+    /// Returns a number in `0..bound` with all numbers equally likely, the bound has to be
+    /// positive
+    pub fn below(&mut self, bound: usize) -> usize {
+        let bound = bound as u64;
+        // the lowest 2^64 % bound values would make the smallest numbers more likely
+        let rejected = bound.wrapping_neg() % bound;
+        loop {
+            let value = self.next_u64();
+            if value >= rejected {
+                break (value % bound) as usize;
+            }
+        }
+    }
+
+    /// Returns a number in `min..=max`
+    pub fn between(&mut self, min: usize, max: usize) -> usize {
+        min + self.below(max - min + 1)
+    }
+
+    pub fn chance(&mut self, probability: f64) -> bool {
+        let uniform = (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64;
+        uniform < probability
+    }
+
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        (1..items.len()).rev().for_each(|i| {
+            let j = self.below(i + 1);
+            items.swap(i, j);
+        });
+    }
+}
+
+/// Generator of syntactically valid puzzle inputs
+///
+/// Each day implements this for its own generator type whose fields tune the generated input,
+/// e.g. its size
+pub trait InputGenerator {
+    fn generate(&self, rng: &mut Rng) -> String;
+
+    /// Sets the field(s) that scale the generated input, e.g. its number of lines
+    fn set_size(&mut self, size: usize);
+
+    /// Default generator scaled to the given size
+    fn with_size(size: usize) -> Self
+    where
+        Self: Default + Sized,
+    {
+        let mut generator = Self::default();
+        generator.set_size(size);
+        generator
+    }
+
+    fn generate_with_seed(&self, seed: u64) -> String {
+        self.generate(&mut Rng::new(seed))
+    }
+
+    /// Leaks the generated input for the `process` functions of the days which require static
+    /// inputs, only meant for tests and benchmarks
+    fn generate_static(&self, seed: u64) -> &'static str {
+        Box::leak(self.generate_with_seed(seed).into_boxed_str())
+    }
+}
+
+/// Number of seeds the tests of the days generate inputs for
+pub const TEST_SEEDS: u64 = 20;
+
+/// Runs the check on the inputs generated for each of the [`TEST_SEEDS`] seeds
+pub fn check_generated_inputs<G, F>(generator: &G, check: F) -> Result<(), AocError>
+where
+    G: InputGenerator,
+    F: FnMut(&'static str) -> Result<(), AocError>,
+{
+    (0..TEST_SEEDS)
+        .map(|seed| generator.generate_static(seed))
+        .try_for_each(check)
+}
+
+/// Defines a `test_generated_inputs` test that runs each of the given `process` functions on
+/// the inputs of the generator, e.g.
+/// `test_generated_inputs!(Generator::with_size(20), part1::process, part2::process);`
+#[macro_export]
+macro_rules! test_generated_inputs {
+    ($generator:expr, $($process:path),+ $(,)?) => {
+        #[test]
+        fn test_generated_inputs() -> Result<(), $crate::AocError> {
+            $crate::generator::check_generated_inputs(&$generator, |input| {
+                $($process(input)?;)+
+                Ok(())
+            })
+        }
+    };
+}
+
+/// Corrupts an input by deleting, duplicating or replacing a single character or by truncating
+/// it, used to check that parsers do not panic on almost valid inputs
+pub fn mutate(input: &str, rng: &mut Rng) -> String {
+    const REPLACEMENTS: [char; 10] = [' ', '\n', '0', '9', '#', '.', ',', '-', 'x', '\u{e9}'];
+    let mut chars: Vec<char> = input.chars().collect();
+    if chars.is_empty() {
+        return rng.choose(&REPLACEMENTS).to_string();
+    }
+    let idx = rng.below(chars.len());
+    match rng.below(4) {
+        0 => {
+            chars.remove(idx);
+        }
+        1 => chars.insert(idx, chars[idx]),
+        2 => chars[idx] = *rng.choose(&REPLACEMENTS),
+        _ => chars.truncate(idx),
+    }
+    chars.into_iter().collect()
+}
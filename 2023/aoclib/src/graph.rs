@@ -0,0 +1,433 @@
+use std::borrow::Borrow;
+use std::collections::{BTreeMap, BinaryHeap, HashMap, VecDeque};
+use std::fmt;
+use std::hash::Hash;
+
+use crate::AocError;
+
+/// Graph whose nodes are labelled, the labels are interned to dense indices so that the
+/// algorithms only work on `usize`s
+#[derive(Debug, Clone)]
+pub struct Graph<N> {
+    labels: Vec<N>,
+    indices: HashMap<N, usize>,
+    adjacency: Vec<Vec<usize>>,
+    /// Sources of the edges arriving at each node, so that predecessors need no search
+    reverse_adjacency: Vec<Vec<usize>>,
+    directed: bool,
+}
+
+/// Minimum cut of an undirected graph, splitting its nodes into two non-empty partitions
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MinCut {
+    /// Edges between the partitions, as `(smaller index, larger index)`
+    pub edges: Vec<(usize, usize)>,
+    /// Whether each node belongs to the first partition
+    pub in_first_partition: Vec<bool>,
+    pub sizes: (usize, usize),
+}
+
+impl<N> Graph<N>
+where
+    N: Clone + Eq + Hash,
+{
+    pub fn new_directed() -> Self {
+        Self {
+            labels: Vec::new(),
+            indices: HashMap::new(),
+            adjacency: Vec::new(),
+            reverse_adjacency: Vec::new(),
+            directed: true,
+        }
+    }
+
+    pub fn new_undirected() -> Self {
+        Self {
+            directed: false,
+            ..Self::new_directed()
+        }
+    }
+
+    pub fn is_directed(&self) -> bool {
+        self.directed
+    }
+
+    pub fn len(&self) -> usize {
+        self.labels.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.labels.is_empty()
+    }
+
+    /// Returns the index of the node with the label, adding the node if it does not exist yet
+    pub fn add_node(&mut self, label: N) -> usize {
+        if let Some(&idx) = self.indices.get(&label) {
+            return idx;
+        }
+        let idx = self.labels.len();
+        self.indices.insert(label.clone(), idx);
+        self.labels.push(label);
+        self.adjacency.push(Vec::new());
+        self.reverse_adjacency.push(Vec::new());
+        idx
+    }
+
+    /// Adds an edge between the nodes with the labels, adding the nodes if necessary
+    pub fn add_edge(&mut self, from: N, to: N) -> (usize, usize) {
+        let from = self.add_node(from);
+        let to = self.add_node(to);
+        self.add_edge_between(from, to);
+        (from, to)
+    }
+
+    /// Adds an edge between existing nodes, in both directions if the graph is undirected
+    pub fn add_edge_between(&mut self, from: usize, to: usize) {
+        self.adjacency[from].push(to);
+        self.reverse_adjacency[to].push(from);
+        if !self.directed && from != to {
+            self.adjacency[to].push(from);
+            self.reverse_adjacency[from].push(to);
+        }
+    }
+
+    pub fn index_of<Q>(&self, label: &Q) -> Option<usize>
+    where
+        N: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.indices.get(label).copied()
+    }
+
+    pub fn label(&self, idx: usize) -> &N {
+        &self.labels[idx]
+    }
+
+    pub fn labels(&self) -> &[N] {
+        &self.labels
+    }
+
+    /// Targets of the edges leaving the node, in the order the edges were added
+    pub fn neighbours(&self, idx: usize) -> &[usize] {
+        &self.adjacency[idx]
+    }
+
+    /// Nodes with an edge to the given node, in ascending order
+    pub fn predecessors(&self, idx: usize) -> Vec<usize> {
+        let mut predecessors = self.reverse_adjacency[idx].clone();
+        predecessors.sort_unstable();
+        predecessors.dedup();
+        predecessors
+    }
+
+    /// Copy of the graph whose edges can be followed in both directions
+    pub fn to_undirected(&self) -> Self {
+        if !self.directed {
+            return self.clone();
+        }
+        let mut undirected = Self {
+            adjacency: vec![Vec::new(); self.len()],
+            reverse_adjacency: vec![Vec::new(); self.len()],
+            directed: false,
+            ..self.clone()
+        };
+        self.adjacency
+            .iter()
+            .enumerate()
+            .for_each(|(from, targets)| {
+                targets
+                    .iter()
+                    .for_each(|&to| undirected.add_edge_between(from, to))
+            });
+        undirected
+    }
+
+    /// Returns whether each node can be reached from any of the start nodes
+    pub fn reachable_from(&self, starts: &[usize]) -> Vec<bool> {
+        let mut reachable = vec![false; self.len()];
+        let mut stack = starts.to_vec();
+        while let Some(node) = stack.pop() {
+            if !reachable[node] {
+                reachable[node] = true;
+                stack.extend(&self.adjacency[node]);
+            }
+        }
+        reachable
+    }
+
+    /// Finds a path with the fewest edges between two nodes, including both ends
+    pub fn shortest_path(&self, from: usize, to: usize) -> Option<Vec<usize>> {
+        let mut previous = vec![None; self.len()];
+        previous[from] = Some(from);
+        let mut queue = VecDeque::from([from]);
+        while let Some(node) = queue.pop_front() {
+            if node == to {
+                let mut path = vec![to];
+                while let Some(&last) = path.last().filter(|&&last| last != from) {
+                    path.push(previous[last].expect("visited nodes have a predecessor"));
+                }
+                path.reverse();
+                return Some(path);
+            }
+            for &next in &self.adjacency[node] {
+                if previous[next].is_none() {
+                    previous[next] = Some(node);
+                    queue.push_back(next);
+                }
+            }
+        }
+        None
+    }
+
+    /// Sorts all nodes so that every edge points from an earlier to a later node
+    pub fn topological_sort(&self) -> Result<Vec<usize>, AocError>
+    where
+        N: fmt::Debug,
+    {
+        self.topological_sort_from(&(0..self.len()).collect::<Vec<_>>())
+    }
+
+    /// Sorts the nodes reachable from the start nodes so that every edge points from an earlier
+    /// to a later node
+    ///
+    /// Returns a [`AocError::LogicError`] naming the nodes of a cycle if there is no such order
+    pub fn topological_sort_from(&self, starts: &[usize]) -> Result<Vec<usize>, AocError>
+    where
+        N: fmt::Debug,
+    {
+        if !self.directed {
+            return Err(AocError::LogicError(
+                "Topological sorting is only supported for directed graphs".to_string(),
+            ));
+        }
+        #[derive(Clone, Copy, PartialEq, Eq)]
+        enum Mark {
+            Unvisited,
+            InProgress,
+            Done,
+        }
+        let mut marks = vec![Mark::Unvisited; self.len()];
+        let mut finished = Vec::new();
+        for &start in starts {
+            if marks[start] != Mark::Unvisited {
+                continue;
+            }
+            // nodes on the current path with the index of their next edge to follow
+            let mut path = vec![(start, 0)];
+            marks[start] = Mark::InProgress;
+            while let Some((node, edge)) = path.last_mut() {
+                let node = *node;
+                let Some(&next) = self.adjacency[node].get(*edge) else {
+                    marks[node] = Mark::Done;
+                    finished.push(node);
+                    path.pop();
+                    continue;
+                };
+                *edge += 1;
+                match marks[next] {
+                    Mark::Unvisited => {
+                        marks[next] = Mark::InProgress;
+                        path.push((next, 0));
+                    }
+                    Mark::InProgress => {
+                        let cycle_start = path.iter().position(|&(node, _)| node == next).unwrap();
+                        let cycle: Vec<_> = path[cycle_start..]
+                            .iter()
+                            .chain(&path[cycle_start..cycle_start + 1])
+                            .map(|&(node, _)| format!("{:?}", self.labels[node]))
+                            .collect();
+                        return Err(AocError::LogicError(format!(
+                            "Graph contains the cycle {}",
+                            cycle.join(" -> ")
+                        )));
+                    }
+                    Mark::Done => {}
+                }
+            }
+        }
+        finished.reverse();
+        Ok(finished)
+    }
+
+    /// Counts the distinct paths from one node to another in an acyclic graph
+    pub fn count_paths(&self, from: usize, to: usize) -> Result<u64, AocError>
+    where
+        N: fmt::Debug,
+    {
+        let mut paths = vec![0u64; self.len()];
+        paths[from] = 1;
+        for node in self.topological_sort_from(&[from])? {
+            for &next in &self.adjacency[node] {
+                paths[next] = paths[next].checked_add(paths[node]).ok_or_else(|| {
+                    AocError::MathError(format!(
+                        "Too many paths from {:?} to {:?}",
+                        self.labels[from], self.labels[to]
+                    ))
+                })?;
+            }
+        }
+        Ok(paths[to])
+    }
+
+    /// Finds the strongly connected components with Tarjan's algorithm
+    ///
+    /// Components are returned in reverse topological order, i.e. edges between components
+    /// point from later to earlier ones
+    pub fn strongly_connected_components(&self) -> Vec<Vec<usize>> {
+        const UNVISITED: usize = usize::MAX;
+        let mut order = vec![UNVISITED; self.len()];
+        let mut low_link = vec![0; self.len()];
+        let mut on_stack = vec![false; self.len()];
+        let mut stack = Vec::new();
+        let mut components = Vec::new();
+        let mut next_order = 0;
+        for root in 0..self.len() {
+            if order[root] != UNVISITED {
+                continue;
+            }
+            let mut path = vec![(root, 0)];
+            order[root] = next_order;
+            low_link[root] = next_order;
+            next_order += 1;
+            stack.push(root);
+            on_stack[root] = true;
+            while let Some((node, edge)) = path.last_mut() {
+                let node = *node;
+                if let Some(&next) = self.adjacency[node].get(*edge) {
+                    *edge += 1;
+                    if order[next] == UNVISITED {
+                        order[next] = next_order;
+                        low_link[next] = next_order;
+                        next_order += 1;
+                        stack.push(next);
+                        on_stack[next] = true;
+                        path.push((next, 0));
+                    } else if on_stack[next] {
+                        low_link[node] = low_link[node].min(order[next]);
+                    }
+                    continue;
+                }
+                path.pop();
+                if let Some(&(parent, _)) = path.last() {
+                    low_link[parent] = low_link[parent].min(low_link[node]);
+                }
+                if low_link[node] == order[node] {
+                    let mut component = Vec::new();
+                    while let Some(member) = stack.pop() {
+                        on_stack[member] = false;
+                        component.push(member);
+                        if member == node {
+                            break;
+                        }
+                    }
+                    component.sort();
+                    components.push(component);
+                }
+            }
+        }
+        components
+    }
+
+    /// Finds a cut with the fewest edges using the Stoer–Wagner algorithm
+    ///
+    /// Parallel edges count individually, self loops are ignored
+    pub fn min_cut(&self) -> Result<MinCut, AocError> {
+        if self.directed {
+            return Err(AocError::LogicError(
+                "Minimum cuts are only supported for undirected graphs".to_string(),
+            ));
+        }
+        if self.len() < 2 {
+            return Err(AocError::MathError(format!(
+                "Cannot cut a graph with {} nodes",
+                self.len()
+            )));
+        }
+        let mut weights: Vec<BTreeMap<usize, usize>> = self
+            .adjacency
+            .iter()
+            .enumerate()
+            .map(|(node, neighbours)| {
+                neighbours
+                    .iter()
+                    .filter(|&&neighbour| neighbour != node)
+                    .fold(BTreeMap::new(), |mut weights, &neighbour| {
+                        *weights.entry(neighbour).or_default() += 1;
+                        weights
+                    })
+            })
+            .collect();
+        let mut members: Vec<Vec<usize>> = (0..self.len()).map(|node| vec![node]).collect();
+        let mut active: Vec<usize> = (0..self.len()).collect();
+        let mut best: Option<(usize, Vec<usize>)> = None;
+        while active.len() > 1 {
+            let (s, t, cut_weight) = maximum_adjacency_phase(&weights, &active);
+            if best.as_ref().is_none_or(|(weight, _)| cut_weight < *weight) {
+                best = Some((cut_weight, members[t].clone()));
+            }
+            // merge t into s
+            std::mem::take(&mut weights[t])
+                .into_iter()
+                .for_each(|(neighbour, weight)| {
+                    weights[neighbour].remove(&t);
+                    if neighbour != s {
+                        *weights[s].entry(neighbour).or_default() += weight;
+                        *weights[neighbour].entry(s).or_default() += weight;
+                    }
+                });
+            let merged = std::mem::take(&mut members[t]);
+            members[s].extend(merged);
+            active.retain(|&node| node != t);
+        }
+
+        let (_, first_partition) = best.expect("at least one phase for two nodes");
+        let mut in_first_partition = vec![false; self.len()];
+        first_partition
+            .iter()
+            .for_each(|&node| in_first_partition[node] = true);
+        let edges = self
+            .adjacency
+            .iter()
+            .enumerate()
+            .flat_map(|(node, neighbours)| neighbours.iter().map(move |&other| (node, other)))
+            .filter(|&(node, other)| {
+                node < other && in_first_partition[node] != in_first_partition[other]
+            })
+            .collect();
+        Ok(MinCut {
+            edges,
+            in_first_partition,
+            sizes: (first_partition.len(), self.len() - first_partition.len()),
+        })
+    }
+}
+
+/// Adds the active nodes one by one, always picking the one most tightly connected to the
+/// nodes added so far
+///
+/// Returns the last two nodes added and the weight of the cut between the last one and all
+/// other nodes
+fn maximum_adjacency_phase(
+    weights: &[BTreeMap<usize, usize>],
+    active: &[usize],
+) -> (usize, usize, usize) {
+    let mut connectivity = vec![0; weights.len()];
+    let mut added = vec![false; weights.len()];
+    let mut queue: BinaryHeap<(usize, usize)> = active.iter().map(|&node| (0, node)).collect();
+    let mut order = Vec::with_capacity(active.len());
+    while let Some((weight, node)) = queue.pop() {
+        if added[node] || weight != connectivity[node] {
+            continue;
+        }
+        added[node] = true;
+        order.push(node);
+        weights[node].iter().for_each(|(&neighbour, &edge_weight)| {
+            if !added[neighbour] {
+                connectivity[neighbour] += edge_weight;
+                queue.push((connectivity[neighbour], neighbour));
+            }
+        });
+    }
+    let t = order[order.len() - 1];
+    (order[order.len() - 2], t, connectivity[t])
+}
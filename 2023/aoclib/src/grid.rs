@@ -1,9 +1,11 @@
+use std::fmt;
+
 use crate::position::Position;
 use crate::AocError;
 
 use nom::{character::complete::line_ending, multi::many1, sequence::terminated, IResult};
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Grid<T>
 where
     T: Clone + Eq,
@@ -21,7 +23,12 @@ where
 {
     pub fn new(element_rows: Vec<Vec<T>>) -> Result<Self, AocError> {
         let rows = element_rows.len();
-        let cols = element_rows[0].len();
+        let cols = element_rows.first().map_or(0, |row| row.len());
+        if cols == 0 {
+            return Err(AocError::ParseError(
+                "A grid must contain at least one element".to_string(),
+            ));
+        }
         element_rows.iter().try_for_each(|row| {
             if row.len() != cols {
                 return Err(AocError::ParseError(
@@ -114,12 +121,23 @@ where
     }
 
     pub fn print(&self) {
-        self.elements.chunks(self.cols).for_each(|row| {
-            println!(
+        println!("{}", self);
+    }
+}
+
+impl<T> fmt::Display for Grid<T>
+where
+    T: Clone + Eq,
+    for<'a> &'a T: Into<char>,
+{
+    /// Writes the grid in the format it is parsed from, i.e. one line per row
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.elements.chunks(self.cols).try_for_each(|row| {
+            writeln!(
+                f,
                 "{}",
                 row.iter().map(|element| element.into()).collect::<String>()
             )
-        });
-        println!();
+        })
     }
 }
@@ -0,0 +1,93 @@
+use std::collections::HashMap;
+
+use crate::direction::Direction;
+use crate::grid::Grid;
+use crate::position::Position;
+
+/// Graph of the junctions of a maze on a grid in which every corridor between two junctions is
+/// contracted into a single weighted edge
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JunctionGraph {
+    pub junctions: Vec<Position>,
+    /// Outgoing edges of every junction as the index of the junction at the other end of the
+    /// corridor and the number of steps through it
+    pub edges: Vec<Vec<(usize, usize)>>,
+}
+
+impl JunctionGraph {
+    /// Contracts the corridors of the grid
+    ///
+    /// `can_step` decides whether it is possible to step from one tile in a direction onto the
+    /// next one. Every tile with at least three neighbours it is connected to in any direction
+    /// is a junction, as are the given points of interest like the entrance of the maze.
+    /// Corridors that end without reaching a junction are dropped.
+    pub fn contract<T>(
+        grid: &Grid<T>,
+        points_of_interest: &[Position],
+        can_step: impl Fn(&T, Direction, &T) -> bool,
+    ) -> Self
+    where
+        T: Clone + Eq,
+        for<'a> &'a T: Into<char>,
+    {
+        let step = |from: &Position, direction: Direction| -> Option<Position> {
+            let to = from.in_direction(direction)?;
+            let from_tile = grid.get_pos(from)?;
+            let to_tile = grid.get_pos(&to)?;
+            can_step(from_tile, direction, to_tile).then_some(to)
+        };
+        let is_junction = |pos: &Position| {
+            Direction::each()
+                .filter(|&direction| {
+                    step(pos, direction).is_some()
+                        || pos
+                            .in_direction(direction)
+                            .and_then(|neighbour| step(&neighbour, direction.opposite()))
+                            .is_some()
+                })
+                .count()
+                >= 3
+        };
+        let mut junctions: Vec<Position> = points_of_interest.to_vec();
+        (0..grid.rows).for_each(|row| {
+            (0..grid.cols).for_each(|col| {
+                let pos = Position::new(row, col);
+                if !junctions.contains(&pos) && is_junction(&pos) {
+                    junctions.push(pos);
+                }
+            })
+        });
+        let junction_indices: HashMap<Position, usize> = junctions
+            .iter()
+            .enumerate()
+            .map(|(idx, &pos)| (pos, idx))
+            .collect();
+        let edges = junctions
+            .iter()
+            .map(|junction| {
+                Direction::each()
+                    .filter_map(|direction| {
+                        let mut previous = *junction;
+                        let mut current = step(junction, direction)?;
+                        let mut length = 1;
+                        while !junction_indices.contains_key(&current) {
+                            // outside of junctions there is at most one way to continue
+                            let next = Direction::each()
+                                .filter_map(|direction| step(&current, direction))
+                                .find(|&next| next != previous)?;
+                            previous = current;
+                            current = next;
+                            length += 1;
+                        }
+                        Some((junction_indices[&current], length))
+                    })
+                    .collect()
+            })
+            .collect();
+        Self { junctions, edges }
+    }
+
+    pub fn index_of(&self, pos: &Position) -> Option<usize> {
+        self.junctions.iter().position(|junction| junction == pos)
+    }
+}
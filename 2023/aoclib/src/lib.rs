@@ -4,9 +4,21 @@ use std::string::ParseError;
 
 use thiserror::Error;
 
+use crate::assumptions::{format_violations, AssumptionViolation};
+
+pub mod assumptions;
+pub mod cuboid;
 pub mod direction;
+pub mod dot;
+pub mod generator;
+pub mod graph;
 pub mod grid;
+pub mod junction_graph;
+pub mod linear_algebra;
+pub mod modular;
+pub mod point3;
 pub mod position;
+pub mod rational;
 
 #[derive(Debug, Error)]
 pub enum AocError {
@@ -16,6 +28,8 @@ pub enum AocError {
     MathError(String),
     #[error("Logic Error: {0}")]
     LogicError(String),
+    #[error("Unsupported Input: {}", format_violations(.0))]
+    UnsupportedInput(Vec<AssumptionViolation>),
     #[error("IO Error: {0}")]
     IoError(#[from] io::Error),
     #[error("Unknown error")]
@@ -39,3 +53,388 @@ impl<I: std::fmt::Debug> From<nom::Err<nom::error::Error<I>>> for AocError {
         AocError::ParseError(format!("(Nom) {}", e))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::cuboid::Cuboid;
+    use crate::dot::Dot;
+    use crate::generator::{mutate, Rng};
+    use crate::graph::Graph;
+    use crate::grid::Grid;
+    use crate::linear_algebra::solve;
+    use crate::modular::solve_congruences;
+    use crate::point3::Point3;
+    use crate::rational::Rational;
+    use crate::AocError;
+    use nom::{branch::alt, character::complete::char as char_parser, combinator::value, IResult};
+    use proptest::prelude::*;
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    enum Tile {
+        Empty,
+        Wall,
+    }
+
+    impl From<&Tile> for char {
+        fn from(tile: &Tile) -> Self {
+            match tile {
+                Tile::Empty => '.',
+                Tile::Wall => '#',
+            }
+        }
+    }
+
+    fn parse_tile(input: &str) -> IResult<&str, Tile> {
+        alt((
+            value(Tile::Empty, char_parser('.')),
+            value(Tile::Wall, char_parser('#')),
+        ))(input)
+    }
+
+    fn tile_rows() -> impl Strategy<Value = Vec<Vec<Tile>>> {
+        (1..8usize, 1..8usize).prop_flat_map(|(rows, cols)| {
+            prop::collection::vec(
+                prop::collection::vec(prop_oneof![Just(Tile::Empty), Just(Tile::Wall)], cols),
+                rows,
+            )
+        })
+    }
+
+    #[test]
+    fn test_empty_grid() {
+        assert!(matches!(
+            Grid::<Tile>::new(Vec::new()),
+            Err(AocError::ParseError(_))
+        ));
+        assert!(matches!(
+            Grid::<Tile>::new(vec![Vec::new()]),
+            Err(AocError::ParseError(_))
+        ));
+    }
+
+    #[test]
+    fn test_cuboid() -> Result<(), AocError> {
+        let (_, corner) = Point3::parse("2,0,5")?;
+        let (_, opposite_corner) = Point3::parse("0,1,-1")?;
+        let cuboid = Cuboid::new(corner, opposite_corner);
+        assert_eq!(Point3::new(0, 0, -1), cuboid.min);
+        assert_eq!(Point3::new(3, 2, 7), cuboid.size());
+        assert_eq!(cuboid.volume() as usize, cuboid.points().count());
+        assert_eq!(6, cuboid.footprint().count());
+        assert!(cuboid.contains(&Point3::new(1, 1, 0)));
+        let above = cuboid.translated(Point3::new(1, 0, 7));
+        assert!(cuboid.overlaps_xy(&above));
+        assert!(!cuboid.overlaps(&above));
+        assert!(cuboid.overlaps(&above.translated(Point3::new(0, 0, -1))));
+        Ok(())
+    }
+
+    #[test]
+    fn test_solve() -> Result<(), AocError> {
+        let r = |n: i128| Rational::from(n);
+        let matrix = vec![
+            vec![r(0), r(2), r(1)],
+            vec![r(1), r(-1), r(0)],
+            vec![r(3), r(0), r(-1)],
+        ];
+        let solution = solve(&matrix, &[r(7), r(-1), r(1)])?;
+        assert_eq!(vec![r(6) / r(5), r(11) / r(5), r(13) / r(5)], solution);
+        let singular = vec![vec![r(1), r(2)], vec![r(2), r(4)]];
+        assert!(matches!(
+            solve(&singular, &[r(1), r(2)]),
+            Err(AocError::MathError(_))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_solve_congruences() -> Result<(), AocError> {
+        assert_eq!(
+            Some((23, 105)),
+            solve_congruences(&[(2, 3), (3, 5), (2, 7)])?
+        );
+        assert_eq!(Some((10, 12)), solve_congruences(&[(4, 6), (2, 4)])?);
+        assert_eq!(None, solve_congruences(&[(1, 6), (2, 4)])?);
+        assert_eq!(Some((0, 1)), solve_congruences(&[])?);
+        assert!(matches!(
+            solve_congruences(&[(0, 0)]),
+            Err(AocError::MathError(_))
+        ));
+        let large = [(1, u64::MAX - 58), (2, u64::MAX - 82)];
+        assert!(matches!(
+            solve_congruences(&large),
+            Err(AocError::MathError(_))
+        ));
+        let (residue, modulus) =
+            solve_congruences(&[(4013 - 1, 4013), (u32::MAX as u64, 4001 * 3851)])?.unwrap();
+        assert_eq!(4013 * 4001 * 3851, modulus);
+        assert_eq!(
+            (4012, u32::MAX as u64 % (4001 * 3851)),
+            (residue % 4013, residue % (4001 * 3851))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_rational_overflow() -> Result<(), AocError> {
+        let big = Rational::from(i128::MAX / 2);
+        assert!(matches!(big.checked_mul(&big), Err(AocError::MathError(_))));
+        assert!(matches!(
+            Rational::new(1, 0i128),
+            Err(AocError::MathError(_))
+        ));
+        assert!(big > Rational::new(i128::MAX / 2 - 1, 1)?);
+        assert!(
+            Rational::new(i128::MAX - 1, i128::MAX)?
+                < Rational::new(i128::MAX - 2, i128::MAX - 1)?.max(Rational::one())
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_min_cut() -> Result<(), AocError> {
+        let mut graph = Graph::new_undirected();
+        // two triangles joined by a single bridge
+        [
+            ("a", "b"),
+            ("b", "c"),
+            ("c", "a"),
+            ("d", "e"),
+            ("e", "f"),
+            ("f", "d"),
+            ("c", "d"),
+        ]
+        .into_iter()
+        .for_each(|(from, to)| {
+            graph.add_edge(from, to);
+        });
+        let cut = graph.min_cut()?;
+        assert_eq!(vec![(2, 3)], cut.edges);
+        assert_eq!((3, 3), cut.sizes);
+        assert_eq!(
+            cut.in_first_partition[graph.index_of(&"a").unwrap()],
+            cut.in_first_partition[graph.index_of(&"c").unwrap()]
+        );
+        assert!(matches!(
+            Graph::<&str>::new_directed().min_cut(),
+            Err(AocError::LogicError(_))
+        ));
+        Ok(())
+    }
+
+    fn directed_graph(edges: &[(&'static str, &'static str)]) -> Graph<&'static str> {
+        let mut graph = Graph::new_directed();
+        edges.iter().for_each(|&(from, to)| {
+            graph.add_edge(from, to);
+        });
+        graph
+    }
+
+    #[test]
+    fn test_dot() {
+        let mut dot = Dot::digraph("quotes");
+        dot.node("in", &[("shape", "box")])
+            .edge("in", "say \"hi\"", &[])
+            .edge(
+                "in",
+                "back\\slash",
+                &[("label", "a<1"), ("style", "dashed")],
+            );
+        let expected = r#"digraph "quotes" {
+    "in" [shape="box"];
+    "in" -> "say \"hi\"";
+    "in" -> "back\\slash" [label="a<1", style="dashed"];
+}
+"#;
+        assert_eq!(expected, dot.to_string());
+    }
+
+    #[test]
+    fn test_directed_graph() -> Result<(), AocError> {
+        // two paths from a to d, followed by the cycle d -> e -> f -> d
+        let edges = [
+            ("a", "b"),
+            ("a", "c"),
+            ("b", "d"),
+            ("c", "d"),
+            ("d", "e"),
+            ("e", "f"),
+            ("f", "d"),
+        ];
+        let graph = directed_graph(&edges);
+        let [a, b, c, d, e, f] =
+            ["a", "b", "c", "d", "e", "f"].map(|label| graph.index_of(label).unwrap());
+        assert_eq!(
+            vec![vec![d, e, f], vec![b], vec![c], vec![a]],
+            graph.strongly_connected_components()
+        );
+        assert_eq!(vec![b, c, f], graph.predecessors(d));
+        assert_eq!(vec![b, c, e, f], graph.to_undirected().predecessors(d));
+        assert_eq!(
+            vec![false, false, true, true, true, true],
+            graph.reachable_from(&[c])
+        );
+        let Err(AocError::LogicError(message)) = graph.topological_sort_from(&[c]) else {
+            panic!("the cycle is reachable from c");
+        };
+        assert!(
+            message.ends_with("\"d\" -> \"e\" -> \"f\" -> \"d\""),
+            "{}",
+            message
+        );
+
+        let graph = directed_graph(&edges[..6]);
+        let order = graph.topological_sort()?;
+        assert_eq!(vec![a, c, b, d, e, f], order);
+        assert_eq!(2, graph.count_paths(a, f)?);
+        assert_eq!(Some(vec![a, b, d, e]), graph.shortest_path(a, e));
+        assert_eq!(Some(vec![c]), graph.shortest_path(c, c));
+        assert_eq!(None, graph.shortest_path(d, a));
+        assert_eq!(0, graph.count_paths(b, c)?);
+        Ok(())
+    }
+
+    fn random_graph() -> impl Strategy<Value = (usize, Vec<(usize, usize)>)> {
+        (2..8usize).prop_flat_map(|nodes| {
+            (
+                Just(nodes),
+                prop::collection::vec((0..nodes, 0..nodes), 0..20),
+            )
+        })
+    }
+
+    fn count_paths_by_enumeration(graph: &Graph<usize>, from: usize, to: usize) -> u64 {
+        if from == to {
+            return 1;
+        }
+        graph
+            .neighbours(from)
+            .iter()
+            .map(|&next| count_paths_by_enumeration(graph, next, to))
+            .sum()
+    }
+
+    #[test]
+    fn test_rng_below_is_unbiased() {
+        // 2^64 is not a multiple of the bound, the modulo alone would make the lowest third of
+        // the numbers as likely as the other two thirds
+        let bound = 3 << 62;
+        let mut rng = Rng::new(42);
+        let lowest_third = (0..3000).filter(|_| rng.below(bound) < 1 << 62).count();
+        assert!((900..1100).contains(&lowest_third), "{}", lowest_third);
+    }
+
+    proptest! {
+        #[test]
+        fn test_solve_congruences_by_search(congruences in prop::collection::vec((0..30u64, 1..12u64), 0..4)) {
+            let modulus_bound: u64 = congruences.iter().map(|&(_, modulus)| modulus).product();
+            let smallest = (0..modulus_bound)
+                .find(|x| congruences.iter().all(|&(residue, modulus)| x % modulus == residue % modulus));
+            match solve_congruences(&congruences)? {
+                Some((residue, modulus)) => {
+                    prop_assert_eq!(smallest, Some(residue));
+                    prop_assert!(congruences.iter().all(|&(_, m)| modulus % m == 0));
+                    prop_assert!(modulus <= modulus_bound);
+                }
+                None => prop_assert_eq!(None, smallest),
+            }
+        }
+
+        #[test]
+        fn test_directed_graph_algorithms((nodes, edges) in random_graph()) {
+            let mut graph = Graph::new_directed();
+            (0..nodes).for_each(|node| {
+                graph.add_node(node);
+            });
+            edges.iter().for_each(|&(from, to)| graph.add_edge_between(from, to));
+            let reachable: Vec<_> = (0..nodes).map(|node| graph.reachable_from(&[node])).collect();
+            let components = graph.strongly_connected_components();
+            prop_assert_eq!(nodes, components.iter().map(|component| component.len()).sum::<usize>());
+            let mut component_of = vec![0; nodes];
+            components.iter().enumerate().for_each(|(idx, component)| {
+                component.iter().for_each(|&node| component_of[node] = idx);
+            });
+            for from in 0..nodes {
+                for to in 0..nodes {
+                    let mutually_reachable = reachable[from][to] && reachable[to][from];
+                    prop_assert_eq!(mutually_reachable, component_of[from] == component_of[to]);
+                }
+            }
+            let has_cycle = components.iter().any(|component| component.len() > 1)
+                || edges.iter().any(|(from, to)| from == to);
+            match graph.topological_sort() {
+                Ok(order) => {
+                    prop_assert!(!has_cycle);
+                    let mut position = vec![0; nodes];
+                    order.iter().enumerate().for_each(|(idx, &node)| position[node] = idx);
+                    prop_assert!(edges.iter().all(|&(from, to)| position[from] < position[to]));
+                    prop_assert_eq!(
+                        count_paths_by_enumeration(&graph, 0, nodes - 1),
+                        graph.count_paths(0, nodes - 1)?
+                    );
+                }
+                Err(AocError::LogicError(_)) => prop_assert!(has_cycle),
+                Err(e) => prop_assert!(false, "{}", e),
+            }
+        }
+
+        #[test]
+        fn test_min_cut_is_minimal((nodes, edges) in random_graph()) {
+            let mut graph = Graph::new_undirected();
+            (0..nodes).for_each(|node| {
+                graph.add_node(node);
+            });
+            edges.iter().for_each(|&(from, to)| graph.add_edge_between(from, to));
+            let cut = graph.min_cut()?;
+            let cut_size = |in_first: &dyn Fn(usize) -> bool| {
+                edges
+                    .iter()
+                    .filter(|&&(from, to)| in_first(from) != in_first(to))
+                    .count()
+            };
+            // node 0 is always in the first partition, set bits move nodes into the second one
+            let brute_force = (0..1usize << (nodes - 1))
+                .filter(|&mask| mask != 0)
+                .map(|mask| cut_size(&|node| node == 0 || mask & (1 << (node - 1)) == 0))
+                .min()
+                .unwrap();
+            prop_assert_eq!(brute_force, cut.edges.len());
+            prop_assert_eq!(brute_force, cut_size(&|node| cut.in_first_partition[node]));
+            prop_assert!(cut.sizes.0 > 0 && cut.sizes.1 > 0);
+            prop_assert_eq!(nodes, cut.sizes.0 + cut.sizes.1);
+        }
+
+        #[test]
+        fn test_rational_arithmetic(
+            (n1, d1, n2, d2) in (-1000i64..1000, 1i64..1000, -1000i64..1000, 1i64..1000)
+        ) {
+            let a = Rational::new(n1, d1)?;
+            let b = Rational::new(n2, d2)?;
+            prop_assert_eq!(a, (a + b) - b);
+            prop_assert_eq!(a.cmp(&b), (n1 * d2).cmp(&(n2 * d1)));
+            prop_assert_eq!(Rational::new(n1 * d2 + n2 * d1, d1 * d2)?, a + b);
+            if !b.is_zero() {
+                prop_assert_eq!(a, (a / b) * b);
+            }
+        }
+
+        #[test]
+        fn test_grid_round_trip(rows in tile_rows()) {
+            let grid = Grid::new(rows)?;
+            prop_assert_eq!(&grid, &Grid::parse_from(&grid.to_string(), parse_tile)?);
+        }
+
+        #[test]
+        fn test_grid_parser_never_panics(input in "[.#x\n]{0,40}") {
+            let result = Grid::parse_from(&input, parse_tile);
+            prop_assert!(matches!(result, Ok(_) | Err(AocError::ParseError(_))), "{:?}", result);
+        }
+
+        #[test]
+        fn test_grid_parser_never_panics_on_mutated_input(rows in tile_rows(), seed in any::<u64>()) {
+            let input = mutate(&Grid::new(rows)?.to_string(), &mut Rng::new(seed));
+            let result = Grid::parse_from(&input, parse_tile);
+            prop_assert!(matches!(result, Ok(_) | Err(AocError::ParseError(_))), "{:?}", result);
+        }
+    }
+}
@@ -0,0 +1,54 @@
+use crate::rational::{Rational, RationalInteger};
+use crate::AocError;
+
+/// Solves the system of linear equations `matrix * x = rhs` exactly by Gaussian elimination
+///
+/// Returns a [`AocError::MathError`] if the system has no unique solution or if the
+/// calculation overflows
+pub fn solve<T: RationalInteger>(
+    matrix: &[Vec<Rational<T>>],
+    rhs: &[Rational<T>],
+) -> Result<Vec<Rational<T>>, AocError> {
+    let size = matrix.len();
+    if rhs.len() != size || matrix.iter().any(|row| row.len() != size) {
+        return Err(AocError::MathError(format!(
+            "Cannot solve a system of {} equations with {} right hand sides for {} unknowns",
+            size,
+            rhs.len(),
+            matrix.first().map_or(0, |row| row.len())
+        )));
+    }
+    // augmented matrix with the right hand side as last column
+    let mut rows: Vec<Vec<Rational<T>>> = matrix
+        .iter()
+        .zip(rhs)
+        .map(|(row, &value)| row.iter().copied().chain([value]).collect())
+        .collect();
+    for col in 0..size {
+        let pivot_row =
+            (col..size)
+                .find(|&row| !rows[row][col].is_zero())
+                .ok_or(AocError::MathError(format!(
+                    "Singular system, no pivot for unknown {}",
+                    col
+                )))?;
+        rows.swap(col, pivot_row);
+        let pivot = rows[col][col];
+        rows[col] = rows[col]
+            .iter()
+            .map(|value| value.checked_div(&pivot))
+            .collect::<Result<_, _>>()?;
+        for row in 0..size {
+            let factor = rows[row][col];
+            if row == col || factor.is_zero() {
+                continue;
+            }
+            rows[row] = rows[row]
+                .iter()
+                .zip(&rows[col])
+                .map(|(value, pivot_value)| value.checked_sub(&factor.checked_mul(pivot_value)?))
+                .collect::<Result<_, _>>()?;
+        }
+    }
+    Ok(rows.into_iter().map(|row| row[size]).collect())
+}
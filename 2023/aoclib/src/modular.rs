@@ -0,0 +1,43 @@
+use num::integer::{ExtendedGcd, Integer};
+
+use crate::AocError;
+
+/// Combines congruences `x ≡ residue (mod modulus)` into a single one with the least common
+/// multiple of the moduli as its modulus, the moduli do not need to be coprime
+///
+/// Returns `None` if the congruences contradict each other
+pub fn solve_congruences(congruences: &[(u64, u64)]) -> Result<Option<(u64, u64)>, AocError> {
+    congruences
+        .iter()
+        .try_fold(Some((0, 1)), |combined, &(residue, modulus)| {
+            let Some((combined_residue, combined_modulus)) = combined else {
+                return Ok(None);
+            };
+            if modulus == 0 {
+                return Err(AocError::MathError(
+                    "Congruences need a positive modulus".to_string(),
+                ));
+            }
+            let (r1, m1) = (combined_residue as i128, combined_modulus as i128);
+            let (r2, m2) = ((residue % modulus) as i128, modulus as i128);
+            let ExtendedGcd { gcd, x, .. } = m1.extended_gcd(&m2);
+            if (r2 - r1) % gcd != 0 {
+                return Ok(None);
+            }
+            let step = m2 / gcd;
+            let lcm = m1
+                .checked_mul(step)
+                .and_then(|lcm| u64::try_from(lcm).ok())
+                .ok_or_else(|| {
+                    AocError::MathError(format!(
+                        "Combined modulus of {} and {} is too large",
+                        combined_modulus, modulus
+                    ))
+                })?;
+            // m1 * x ≡ gcd (mod m2), so adding m1 * k to r1 with this k hits r2
+            let k = ((r2 - r1) / gcd).rem_euclid(step) as u128 * x.rem_euclid(step) as u128
+                % step as u128;
+            let solution = (r1 as u128 + combined_modulus as u128 * k) % lcm as u128;
+            Ok(Some((solution as u64, lcm)))
+        })
+}
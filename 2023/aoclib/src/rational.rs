@@ -0,0 +1,231 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+use num::{CheckedAdd, CheckedMul, CheckedSub, Integer, Signed};
+
+use crate::AocError;
+
+/// Integer types a [`Rational`] can be built from
+pub trait RationalInteger:
+    Integer + Signed + CheckedAdd + CheckedSub + CheckedMul + Copy + fmt::Display
+{
+}
+
+impl<T> RationalInteger for T where
+    T: Integer + Signed + CheckedAdd + CheckedSub + CheckedMul + Copy + fmt::Display
+{
+}
+
+/// Exact fraction which is always kept in lowest terms with a positive denominator
+///
+/// The checked operations report overflows as [`AocError::MathError`], the operators panic on
+/// overflow instead
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rational<T = i128> {
+    numerator: T,
+    denominator: T,
+}
+
+fn overflow<T: RationalInteger>(operation: &str, a: &Rational<T>, b: &Rational<T>) -> AocError {
+    AocError::MathError(format!(
+        "Overflow when calculating {} {} {}",
+        a, operation, b
+    ))
+}
+
+impl<T: RationalInteger> Rational<T> {
+    pub fn new(numerator: T, denominator: T) -> Result<Self, AocError> {
+        if denominator.is_zero() {
+            return Err(AocError::MathError(format!(
+                "Denominator of {}/{} is zero",
+                numerator, denominator
+            )));
+        }
+        let gcd = numerator.gcd(&denominator);
+        let sign = denominator.signum();
+        Ok(Self {
+            numerator: numerator / gcd * sign,
+            denominator: denominator / gcd * sign,
+        })
+    }
+
+    pub fn from_integer(integer: T) -> Self {
+        Self {
+            numerator: integer,
+            denominator: T::one(),
+        }
+    }
+
+    pub fn zero() -> Self {
+        Self::from_integer(T::zero())
+    }
+
+    pub fn one() -> Self {
+        Self::from_integer(T::one())
+    }
+
+    pub fn numerator(&self) -> T {
+        self.numerator
+    }
+
+    pub fn denominator(&self) -> T {
+        self.denominator
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.numerator.is_zero()
+    }
+
+    pub fn is_integer(&self) -> bool {
+        self.denominator.is_one()
+    }
+
+    /// Returns the value if it is an integer
+    pub fn to_integer(&self) -> Option<T> {
+        self.is_integer().then_some(self.numerator)
+    }
+
+    pub fn checked_add(&self, other: &Self) -> Result<Self, AocError> {
+        // only multiply with the part of the denominators that is not shared to avoid overflows
+        let gcd = self.denominator.gcd(&other.denominator);
+        let numerator = self
+            .numerator
+            .checked_mul(&(other.denominator / gcd))
+            .zip(other.numerator.checked_mul(&(self.denominator / gcd)))
+            .and_then(|(a, b)| a.checked_add(&b));
+        let denominator = self.denominator.checked_mul(&(other.denominator / gcd));
+        match numerator.zip(denominator) {
+            Some((numerator, denominator)) => Self::new(numerator, denominator),
+            None => Err(overflow("+", self, other)),
+        }
+    }
+
+    pub fn checked_sub(&self, other: &Self) -> Result<Self, AocError> {
+        self.checked_add(&-*other)
+    }
+
+    pub fn checked_mul(&self, other: &Self) -> Result<Self, AocError> {
+        // cancel crosswise first to keep the products small
+        let gcd_1 = self.numerator.gcd(&other.denominator);
+        let gcd_2 = other.numerator.gcd(&self.denominator);
+        let numerator = (self.numerator / gcd_1).checked_mul(&(other.numerator / gcd_2));
+        let denominator = (self.denominator / gcd_2).checked_mul(&(other.denominator / gcd_1));
+        match numerator.zip(denominator) {
+            Some((numerator, denominator)) => Self::new(numerator, denominator),
+            None => Err(overflow("*", self, other)),
+        }
+    }
+
+    pub fn checked_div(&self, other: &Self) -> Result<Self, AocError> {
+        if other.is_zero() {
+            return Err(AocError::MathError(format!("Division of {} by zero", self)));
+        }
+        self.checked_mul(&Self {
+            numerator: other.denominator * other.numerator.signum(),
+            denominator: other.numerator.abs(),
+        })
+    }
+}
+
+impl<T: RationalInteger> From<T> for Rational<T> {
+    fn from(integer: T) -> Self {
+        Self::from_integer(integer)
+    }
+}
+
+impl<T: RationalInteger> fmt::Display for Rational<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_integer() {
+            write!(f, "{}", self.numerator)
+        } else {
+            write!(f, "{}/{}", self.numerator, self.denominator)
+        }
+    }
+}
+
+impl<T: RationalInteger> Neg for Rational<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self {
+            numerator: -self.numerator,
+            denominator: self.denominator,
+        }
+    }
+}
+
+impl<T: RationalInteger> Add for Rational<T> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        self.checked_add(&other).unwrap()
+    }
+}
+
+impl<T: RationalInteger> Sub for Rational<T> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        self.checked_sub(&other).unwrap()
+    }
+}
+
+impl<T: RationalInteger> Mul for Rational<T> {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        self.checked_mul(&other).unwrap()
+    }
+}
+
+impl<T: RationalInteger> Div for Rational<T> {
+    type Output = Self;
+
+    fn div(self, other: Self) -> Self {
+        self.checked_div(&other).unwrap()
+    }
+}
+
+impl<T: RationalInteger> PartialOrd for Rational<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Compares two fractions with positive denominators by their continued fractions, which
+/// cannot overflow unlike comparing the cross products
+fn compare_fractions<T: RationalInteger>(
+    numerator_1: T,
+    denominator_1: T,
+    numerator_2: T,
+    denominator_2: T,
+) -> Ordering {
+    let (quotient_1, remainder_1) = numerator_1.div_mod_floor(&denominator_1);
+    let (quotient_2, remainder_2) = numerator_2.div_mod_floor(&denominator_2);
+    match (
+        quotient_1.cmp(&quotient_2),
+        remainder_1.is_zero(),
+        remainder_2.is_zero(),
+    ) {
+        (Ordering::Equal, true, true) => Ordering::Equal,
+        (Ordering::Equal, true, false) => Ordering::Less,
+        (Ordering::Equal, false, true) => Ordering::Greater,
+        // the remainders are smaller than the denominators, compare their reciprocals reversed
+        (Ordering::Equal, false, false) => {
+            compare_fractions(denominator_2, remainder_2, denominator_1, remainder_1)
+        }
+        (ordering, _, _) => ordering,
+    }
+}
+
+impl<T: RationalInteger> Ord for Rational<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        compare_fractions(
+            self.numerator,
+            self.denominator,
+            other.numerator,
+            other.denominator,
+        )
+    }
+}
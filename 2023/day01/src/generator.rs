@@ -0,0 +1,51 @@
+use aoclib::generator::{InputGenerator, Rng};
+
+const DIGIT_NAMES: [&str; 9] = [
+    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+/// Generates calibration documents where every line contains at least one digit
+#[derive(Debug, Clone)]
+pub struct Generator {
+    pub lines: usize,
+    pub max_line_length: usize,
+    /// Probability of a token being a spelled out digit instead of a digit or a letter
+    pub spelled_digit_density: f64,
+}
+
+impl Default for Generator {
+    fn default() -> Self {
+        Self {
+            lines: 1000,
+            max_line_length: 12,
+            spelled_digit_density: 0.15,
+        }
+    }
+}
+
+impl InputGenerator for Generator {
+    fn set_size(&mut self, size: usize) {
+        self.lines = size;
+    }
+
+    fn generate(&self, rng: &mut Rng) -> String {
+        (0..self.lines)
+            .map(|_| {
+                let length = rng.between(1, self.max_line_length.max(1));
+                let digit_idx = rng.below(length);
+                let line: String = (0..length)
+                    .map(|idx| {
+                        if idx == digit_idx || rng.chance(0.2) {
+                            char::from(b'1' + rng.below(9) as u8).to_string()
+                        } else if rng.chance(self.spelled_digit_density) {
+                            rng.choose(&DIGIT_NAMES).to_string()
+                        } else {
+                            char::from(b'a' + rng.below(26) as u8).to_string()
+                        }
+                    })
+                    .collect();
+                line + "\n"
+            })
+            .collect()
+    }
+}
@@ -13,7 +13,7 @@ fn get_calibration_value(line: &str) -> Result<i32, AocError> {
     let first_digit = it
         .next()
         .ok_or(AocError::ParseError(String::from("No digits found")))?;
-    let last_digit = it.last().unwrap_or(first_digit);
+    let last_digit = it.next_back().unwrap_or(first_digit);
     let first_digit = char_to_digit(first_digit)?;
     let last_digit = char_to_digit(last_digit)?;
     Ok(first_digit * 10 + last_digit)
@@ -0,0 +1,47 @@
+use aoclib::generator::{InputGenerator, Rng};
+
+const COLORS: [&str; 3] = ["red", "green", "blue"];
+
+/// Generates the records of games in which cubes are drawn from a bag
+#[derive(Debug, Clone)]
+pub struct Generator {
+    pub games: usize,
+    pub max_sets: usize,
+    pub max_cubes: usize,
+}
+
+impl Default for Generator {
+    fn default() -> Self {
+        Self {
+            games: 100,
+            max_sets: 6,
+            max_cubes: 20,
+        }
+    }
+}
+
+impl InputGenerator for Generator {
+    fn set_size(&mut self, size: usize) {
+        self.games = size;
+    }
+
+    fn generate(&self, rng: &mut Rng) -> String {
+        (1..=self.games)
+            .map(|id| {
+                let sets: Vec<String> = (0..rng.between(1, self.max_sets.max(1)))
+                    .map(|_| {
+                        let mut colors = COLORS;
+                        rng.shuffle(&mut colors);
+                        let color_count = rng.between(1, 3);
+                        colors[..color_count]
+                            .iter()
+                            .map(|color| format!("{} {}", rng.between(1, self.max_cubes), color))
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    })
+                    .collect();
+                format!("Game {}: {}\n", id, sets.join("; "))
+            })
+            .collect()
+    }
+}
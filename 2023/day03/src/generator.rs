@@ -0,0 +1,54 @@
+use aoclib::generator::{InputGenerator, Rng};
+
+const SYMBOLS: &[u8] = b"$%&/+-*#=@";
+
+/// Generates square engine schematics of numbers and symbols
+#[derive(Debug, Clone)]
+pub struct Generator {
+    pub side_length: usize,
+    /// Probability of a number starting at a free cell
+    pub number_density: f64,
+    /// Probability of a symbol at a free cell
+    pub symbol_density: f64,
+}
+
+impl Default for Generator {
+    fn default() -> Self {
+        Self {
+            side_length: 140,
+            number_density: 0.08,
+            symbol_density: 0.04,
+        }
+    }
+}
+
+impl InputGenerator for Generator {
+    fn set_size(&mut self, size: usize) {
+        self.side_length = size;
+    }
+
+    fn generate(&self, rng: &mut Rng) -> String {
+        (0..self.side_length)
+            .map(|_| {
+                let mut row = vec![b'.'; self.side_length];
+                let mut col = 0;
+                while col < self.side_length {
+                    if rng.chance(self.number_density) {
+                        // numbers are at most three digits and never touch another number
+                        let width = rng.between(1, 3).min(self.side_length - col);
+                        row[col] = b'1' + rng.below(9) as u8;
+                        (col + 1..col + width)
+                            .for_each(|idx| row[idx] = b'0' + rng.below(10) as u8);
+                        col += width + 1;
+                    } else {
+                        if rng.chance(self.symbol_density) {
+                            row[col] = *rng.choose(SYMBOLS);
+                        }
+                        col += 1;
+                    }
+                }
+                String::from_utf8(row).unwrap() + "\n"
+            })
+            .collect()
+    }
+}
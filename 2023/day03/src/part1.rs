@@ -105,7 +105,7 @@ pub fn is_adjacent(number_idx: usize, number_width: usize, symbol_idx: usize) ->
 }
 
 fn valid_numbers_from_row(
-    row: &Vec<SchematicPart>,
+    row: &[SchematicPart],
     prev_row: Option<&Vec<SchematicPart>>,
     next_row: Option<&Vec<SchematicPart>>,
 ) -> Result<Vec<i32>, AocError> {
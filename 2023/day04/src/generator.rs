@@ -0,0 +1,51 @@
+use aoclib::generator::{InputGenerator, Rng};
+
+/// Generates scratchcards with distinct two-digit numbers on each side
+#[derive(Debug, Clone)]
+pub struct Generator {
+    pub cards: usize,
+    pub winning_numbers: usize,
+    pub your_numbers: usize,
+}
+
+impl Default for Generator {
+    fn default() -> Self {
+        Self {
+            cards: 200,
+            winning_numbers: 10,
+            your_numbers: 25,
+        }
+    }
+}
+
+fn distinct_numbers(rng: &mut Rng, count: usize) -> String {
+    let mut numbers: Vec<usize> = (1..100).collect();
+    rng.shuffle(&mut numbers);
+    numbers
+        .iter()
+        .take(count.min(numbers.len()))
+        .map(|number| format!("{:2}", number))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+impl InputGenerator for Generator {
+    fn set_size(&mut self, size: usize) {
+        self.cards = size;
+    }
+
+    fn generate(&self, rng: &mut Rng) -> String {
+        let id_width = self.cards.to_string().len();
+        (1..=self.cards)
+            .map(|id| {
+                format!(
+                    "Card {:width$}: {} | {}\n",
+                    id,
+                    distinct_numbers(rng, self.winning_numbers.max(1)),
+                    distinct_numbers(rng, self.your_numbers.max(1)),
+                    width = id_width
+                )
+            })
+            .collect()
+    }
+}
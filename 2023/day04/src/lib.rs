@@ -1,10 +1,14 @@
+pub mod generator;
 pub mod part1;
 pub mod part2;
 
 #[cfg(test)]
 mod tests {
+    use crate::generator::Generator;
     use crate::part1;
     use crate::part2;
+    use aoclib::generator::InputGenerator;
+    use aoclib::test_generated_inputs;
     use aoclib::AocError;
 
     #[test]
@@ -24,4 +28,6 @@ mod tests {
         assert_eq!(expected_output, output);
         Ok(())
     }
+
+    test_generated_inputs!(Generator::with_size(20), part1::process, part2::process);
 }
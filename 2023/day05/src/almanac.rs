@@ -1,16 +1,18 @@
 use std::cmp::{max, min};
+use std::collections::HashMap;
 
+use aoclib::graph::Graph;
 use aoclib::AocError;
 
 use nom::{
     bytes::complete::tag,
-    character::complete::{newline, space1, u64 as u64_parser},
-    multi::separated_list1,
-    sequence::preceded,
+    character::complete::{alpha1, newline, space1, u64 as u64_parser},
+    multi::many0,
+    sequence::{preceded, separated_pair, terminated},
     IResult,
 };
 
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub struct Range {
     pub start: u64,
     pub length: u64,
@@ -54,42 +56,83 @@ impl Range {
     }
 }
 
+/// Maps between categories, each map converts values of its source category into values of
+/// its destination category
 #[derive(Debug)]
 pub struct Almanac {
-    seed_to_soil: Map,
-    soil_to_fertilizer: Map,
-    fertilizer_to_water: Map,
-    water_to_light: Map,
-    light_to_temperature: Map,
-    temperature_to_humidity: Map,
-    humidity_to_location: Map,
+    /// Categories with an edge from the source to the destination of every map
+    categories: Graph<String>,
+    maps: HashMap<(usize, usize), Map>,
 }
 
 impl Almanac {
-    pub fn location_for_seed(&self, seed: u64) -> u64 {
-        let soil = self.seed_to_soil.map(seed);
-        let fertilizer = self.soil_to_fertilizer.map(soil);
-        let water = self.fertilizer_to_water.map(fertilizer);
-        let light = self.water_to_light.map(water);
-        let temperature = self.light_to_temperature.map(light);
-        let humidity = self.temperature_to_humidity.map(temperature);
-        self.humidity_to_location.map(humidity)
-    }
-
-    pub fn location_ranges_for_seed_range(&self, range: &Range) -> Vec<Range> {
-        self.seed_to_soil
-            .map_range(range)
-            .into_iter()
-            .flat_map(|range| self.soil_to_fertilizer.map_range(&range))
-            .flat_map(|range| self.fertilizer_to_water.map_range(&range))
-            .flat_map(|range| self.water_to_light.map_range(&range))
-            .flat_map(|range| self.light_to_temperature.map_range(&range))
-            .flat_map(|range| self.temperature_to_humidity.map_range(&range))
-            .flat_map(|range| self.humidity_to_location.map_range(&range))
-            .collect()
+    fn category(&self, name: &str) -> Result<usize, AocError> {
+        self.categories
+            .index_of(name)
+            .ok_or_else(|| AocError::LogicError(format!("Unknown category: {}", name)))
+    }
+
+    /// Source category, destination category and map of every map
+    pub fn maps(&self) -> impl Iterator<Item = (&str, &str, &Map)> {
+        self.maps.iter().map(|(&(source, destination), map)| {
+            (
+                self.categories.label(source).as_str(),
+                self.categories.label(destination).as_str(),
+                map,
+            )
+        })
+    }
+
+    /// Follows the fewest maps forward from one category to the other, or if they do not lead
+    /// there the fewest maps in any direction
+    fn path(&self, from: usize, to: usize) -> Option<Vec<usize>> {
+        self.categories
+            .shortest_path(from, to)
+            .or_else(|| self.categories.to_undirected().shortest_path(from, to))
+    }
+
+    /// Maps the ranges of one category to all ranges of another one they correspond to
+    ///
+    /// Maps are applied in reverse only if following them forward does not lead to the other
+    /// category. The inverse of a map gives all values that map onto a value, which can be none
+    /// or several.
+    pub fn map_ranges(
+        &self,
+        from: &str,
+        to: &str,
+        ranges: &[Range],
+    ) -> Result<Vec<Range>, AocError> {
+        let path = self
+            .path(self.category(from)?, self.category(to)?)
+            .ok_or_else(|| AocError::LogicError(format!("No maps lead from {} to {}", from, to)))?;
+        let ranges = path.windows(2).fold(ranges.to_vec(), |ranges, step| {
+            let (source, destination) = (step[0], step[1]);
+            match self.maps.get(&(source, destination)) {
+                Some(map) => ranges
+                    .iter()
+                    .flat_map(|range| map.map_range(range))
+                    .collect(),
+                None => {
+                    let map = &self.maps[&(destination, source)];
+                    ranges
+                        .iter()
+                        .flat_map(|range| map.preimage_of_range(range))
+                        .collect()
+                }
+            }
+        });
+        Ok(ranges)
+    }
+
+    /// Maps a value of one category to all values of another one it corresponds to, there is
+    /// exactly one if no map has to be applied in reverse
+    pub fn map_value(&self, from: &str, to: &str, value: u64) -> Result<Vec<u64>, AocError> {
+        let ranges = self.map_ranges(from, to, &[Range::new(value, 1)])?;
+        Ok(ranges.iter().flat_map(Range::iter).collect())
     }
 }
 
+/// Mappings sorted by the start of their sources, values outside all sources map to themselves
 #[derive(Debug)]
 pub struct Map {
     mappings: Vec<Mapping>,
@@ -105,31 +148,57 @@ impl Map {
         value
     }
 
-    pub fn map_range(&self, range: &Range) -> Vec<Range> {
-        let mut mapped_ranges: Vec<Range> = Vec::new();
-        let mut mappings = self.mappings.iter();
-        let mut current_mapping = mappings.next().unwrap();
+    /// Splits the range into the parts covered by a single mapping and the parts covered by none
+    fn split(&self, range: &Range) -> Vec<(Range, Option<&Mapping>)> {
+        let mut parts = Vec::new();
         let mut current_range = *range;
-        loop {
-            if current_mapping.source.is_overlapping_with(&current_range) {
-                let (pre_ol, ol, post_ol) = current_mapping.map_range(&current_range);
-                if let Some(pre_ol) = pre_ol {
-                    mapped_ranges.push(pre_ol);
-                }
-                mapped_ranges.push(ol.unwrap());
-                if let Some(post_ol) = post_ol {
-                    current_range = post_ol
-                } else {
-                    break;
-                }
-            } else if let Some(next_mapping) = mappings.next() {
-                current_mapping = next_mapping;
-            } else {
-                mapped_ranges.push(current_range);
+        for mapping in self.mappings.iter() {
+            if current_range.end() <= mapping.source.start {
                 break;
             }
+            if let Some(overlap) = mapping.source.overlap(&current_range) {
+                if current_range.start < overlap.start {
+                    parts.push((
+                        Range::new(current_range.start, overlap.start - current_range.start),
+                        None,
+                    ));
+                }
+                parts.push((overlap, Some(mapping)));
+                current_range = Range::new(overlap.end(), current_range.end() - overlap.end());
+            }
+        }
+        if !current_range.is_empty() {
+            parts.push((current_range, None));
         }
-        mapped_ranges
+        parts
+    }
+
+    pub fn map_range(&self, range: &Range) -> Vec<Range> {
+        self.split(range)
+            .into_iter()
+            .map(|(part, mapping)| match mapping {
+                Some(mapping) => Range::new(mapping.map(part.start), part.length),
+                None => part,
+            })
+            .collect()
+    }
+
+    /// All values that are mapped into the range
+    pub fn preimage_of_range(&self, range: &Range) -> Vec<Range> {
+        let mapped_into_range = self.mappings.iter().filter_map(|mapping| {
+            mapping.destination.overlap(range).map(|overlap| {
+                Range::new(
+                    mapping.source.start + overlap.start - mapping.destination.start,
+                    overlap.length,
+                )
+            })
+        });
+        let kept_in_range = self
+            .split(range)
+            .into_iter()
+            .filter(|(_, mapping)| mapping.is_none())
+            .map(|(part, _)| part);
+        mapped_into_range.chain(kept_in_range).collect()
     }
 }
 
@@ -151,48 +220,17 @@ impl Mapping {
             value
         }
     }
-
-    fn map_range(&self, range: &Range) -> (Option<Range>, Option<Range>, Option<Range>) {
-        let overlap = self.source.overlap(range);
-        if let Some(overlap) = overlap {
-            let pre_overlap = if range.start < overlap.start {
-                Some(Range {
-                    start: range.start,
-                    length: overlap.start - range.start,
-                })
-            } else {
-                None
-            };
-            let mapped_overlap = Range {
-                start: self.map(overlap.start),
-                length: overlap.length,
-            };
-            let post_overlap = if overlap.end() < range.end() {
-                Some(Range {
-                    start: overlap.end(),
-                    length: range.end() - overlap.end(),
-                })
-            } else {
-                None
-            };
-            (pre_overlap, Some(mapped_overlap), post_overlap)
-        } else if range.end() <= self.source.start {
-            (Some(*range), None, None)
-        } else {
-            (None, None, Some(*range))
-        }
-    }
 }
 
-fn parse_map<'a>(input: &'a str, map_name: &'static str) -> IResult<&'a str, Map> {
-    let (input, _) = newline(input)?;
-    let (input, _) = preceded(tag(map_name), tag(" map:"))(input)?;
+fn parse_map(input: &str) -> IResult<&str, ((&str, &str), Map)> {
     let (input, _) = newline(input)?;
-    let (input, mut mappings) = separated_list1(newline, parse_mapping)(input)?;
+    let (input, categories) =
+        terminated(separated_pair(alpha1, tag("-to-"), alpha1), tag(" map:"))(input)?;
     let (input, _) = newline(input)?;
+    let (input, mut mappings) = many0(terminated(parse_mapping, newline))(input)?;
     mappings.sort_by_key(|mapping| mapping.source.start);
     let map = Map { mappings };
-    Ok((input, map))
+    Ok((input, (categories, map)))
 }
 
 fn parse_mapping(input: &str) -> IResult<&str, Mapping> {
@@ -213,27 +251,29 @@ fn parse_mapping(input: &str) -> IResult<&str, Mapping> {
 }
 
 pub fn parse_almanac(input: &str) -> Result<Almanac, AocError> {
-    let (input, seed_to_soil) = parse_map(input, "seed-to-soil")?;
-    let (input, soil_to_fertilizer) = parse_map(input, "soil-to-fertilizer")?;
-    let (input, fertilizer_to_water) = parse_map(input, "fertilizer-to-water")?;
-    let (input, water_to_light) = parse_map(input, "water-to-light")?;
-    let (input, light_to_temperature) = parse_map(input, "light-to-temperature")?;
-    let (input, temperature_to_humidity) = parse_map(input, "temperature-to-humidity")?;
-    let (rest, humidity_to_location) = parse_map(input, "humidity-to-location")?;
-    if rest.is_empty() {
-        Ok(Almanac {
-            seed_to_soil,
-            soil_to_fertilizer,
-            fertilizer_to_water,
-            water_to_light,
-            light_to_temperature,
-            temperature_to_humidity,
-            humidity_to_location,
-        })
-    } else {
-        Err(AocError::ParseError(format!(
+    let (rest, parsed_maps) = many0(parse_map)(input)?;
+    if !rest.is_empty() {
+        return Err(AocError::ParseError(format!(
             "Parsing Almanac left over rest: {}",
             rest
-        )))
+        )));
+    }
+    let mut categories = Graph::new_directed();
+    let mut maps = HashMap::new();
+    for ((source, destination), map) in parsed_maps {
+        if source == destination {
+            return Err(AocError::ParseError(format!(
+                "Map from {} onto itself",
+                source
+            )));
+        }
+        let categories = categories.add_edge(source.to_string(), destination.to_string());
+        if maps.insert(categories, map).is_some() {
+            return Err(AocError::ParseError(format!(
+                "Map from {} to {} is defined twice",
+                source, destination
+            )));
+        }
     }
+    Ok(Almanac { categories, maps })
 }
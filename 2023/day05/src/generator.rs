@@ -0,0 +1,96 @@
+use aoclib::generator::{InputGenerator, Rng};
+
+const MAP_NAMES: [&str; 7] = [
+    "seed-to-soil",
+    "soil-to-fertilizer",
+    "fertilizer-to-water",
+    "water-to-light",
+    "light-to-temperature",
+    "temperature-to-humidity",
+    "humidity-to-location",
+];
+
+/// Generates almanacs whose maps each permute blocks of the value range
+#[derive(Debug, Clone)]
+pub struct Generator {
+    /// Number of seed ranges, i.e. half the number of values in the seeds line
+    pub seed_ranges: usize,
+    pub mappings_per_map: usize,
+    pub max_value: u64,
+    /// Probability of a block to be left out of its map, i.e. to be mapped onto itself
+    pub unmapped_chance: f64,
+}
+
+impl Default for Generator {
+    fn default() -> Self {
+        Self {
+            seed_ranges: 10,
+            mappings_per_map: 30,
+            max_value: 4_000_000_000,
+            unmapped_chance: 0.0,
+        }
+    }
+}
+
+fn random_u64(rng: &mut Rng, max: u64) -> u64 {
+    rng.next_u64() % max.max(1)
+}
+
+impl InputGenerator for Generator {
+    fn set_size(&mut self, size: usize) {
+        self.mappings_per_map = size;
+    }
+
+    fn generate(&self, rng: &mut Rng) -> String {
+        let max_value = self.max_value.max(self.mappings_per_map as u64 + 1);
+        let seeds: Vec<String> = (0..self.seed_ranges.max(1))
+            .map(|_| {
+                let start = random_u64(rng, max_value);
+                let length = 1 + random_u64(rng, (max_value - start).min(max_value / 10));
+                format!("{} {}", start, length)
+            })
+            .collect();
+        let maps: String = MAP_NAMES
+            .iter()
+            .map(|map_name| {
+                let block_count = self.mappings_per_map.max(1);
+                let mut cuts: Vec<u64> = (1..block_count)
+                    .map(|_| 1 + random_u64(rng, max_value - 1))
+                    .chain([0, max_value])
+                    .collect();
+                cuts.sort();
+                cuts.dedup();
+                let blocks: Vec<(u64, u64)> = cuts
+                    .windows(2)
+                    .map(|window| (window[0], window[1] - window[0]))
+                    .collect();
+                let mut destination_order: Vec<usize> = (0..blocks.len()).collect();
+                rng.shuffle(&mut destination_order);
+                let mut destination_start = 0;
+                let mut mappings: Vec<(u64, u64, u64)> = destination_order
+                    .into_iter()
+                    .map(|block_idx| {
+                        let (source_start, length) = blocks[block_idx];
+                        let mapping = (destination_start, source_start, length);
+                        destination_start += length;
+                        mapping
+                    })
+                    .collect();
+                rng.shuffle(&mut mappings);
+                // keep a mapping if all get dropped, so that every map still moves some values
+                let first_mapping = mappings.first().copied();
+                mappings.retain(|_| !rng.chance(self.unmapped_chance));
+                if mappings.is_empty() {
+                    // map all values onto themselves if there were no blocks at all
+                    mappings.push(first_mapping.unwrap_or((0, 0, max_value)));
+                }
+                let mappings: String = mappings
+                    .into_iter()
+                    .map(|(dst, src, len)| format!("{} {} {}\n", dst, src, len))
+                    .collect();
+                format!("\n{} map:\n{}", map_name, mappings)
+            })
+            .collect();
+        format!("seeds: {}\n{}", seeds.join(" "), maps)
+    }
+}
@@ -1,12 +1,21 @@
 pub mod almanac;
+pub mod generator;
 pub mod part1;
 pub mod part2;
+#[cfg(any(test, feature = "reference"))]
+pub mod reference;
 
 #[cfg(test)]
 mod tests {
+    use crate::almanac::{parse_almanac, Range};
+    use crate::generator::Generator;
     use crate::part1;
     use crate::part2;
+    use crate::reference;
+    use aoclib::generator::{mutate, InputGenerator, Rng};
+    use aoclib::test_generated_inputs;
     use aoclib::AocError;
+    use proptest::prelude::*;
 
     #[test]
     fn test_part1() -> Result<(), AocError> {
@@ -25,4 +34,115 @@ mod tests {
         assert_eq!(expected_output, output);
         Ok(())
     }
+
+    test_generated_inputs!(Generator::with_size(5), part1::process, part2::process);
+
+    #[test]
+    fn test_reordered_and_extra_sections() -> Result<(), AocError> {
+        let input = include_str!("../test_input1.txt");
+        let (seeds, maps) = input.split_once('\n').unwrap();
+        let mut sections: Vec<&str> = maps.split("\n\n").map(str::trim).collect();
+        sections.reverse();
+        sections.insert(3, "location-to-planet map:\n0 60 10");
+        let input = format!("{}\n\n{}\n", seeds, sections.join("\n\n"));
+        let input: &'static str = input.leak();
+        assert_eq!(35, part1::process(input)?);
+        assert_eq!(46, part2::process(input)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_map_between_any_categories() -> Result<(), AocError> {
+        let input = include_str!("../test_input1.txt");
+        let (_, maps) = input.split_once('\n').unwrap();
+        let almanac = parse_almanac(maps)?;
+        assert_eq!(vec![81], almanac.map_value("seed", "water", 79)?);
+        assert_eq!(vec![13], almanac.map_value("location", "seed", 35)?);
+        assert_eq!(vec![81], almanac.map_value("water", "soil", 81)?);
+        assert_eq!(
+            vec![Range::new(82, 1)],
+            almanac.map_ranges("location", "seed", &[Range::new(46, 1)])?
+        );
+        assert!(matches!(
+            almanac.map_value("seed", "planet", 79),
+            Err(AocError::LogicError(_))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_empty_and_unreachable_maps() -> Result<(), AocError> {
+        let almanac = parse_almanac("\na-to-b map:\n\nc-to-d map:\n1 0 5\n")?;
+        assert_eq!(vec![7], almanac.map_value("a", "b", 7)?);
+        assert_eq!(vec![4, 5], almanac.map_value("d", "c", 5)?);
+        assert_eq!(Vec::<u64>::new(), almanac.map_value("d", "c", 0)?);
+        assert!(matches!(
+            almanac.map_value("a", "d", 7),
+            Err(AocError::LogicError(_))
+        ));
+        assert!(matches!(
+            parse_almanac("\na-to-b map:\n\nb-to-a map:\n\na-to-b map:\n"),
+            Err(AocError::ParseError(_))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_prefer_forward_maps() -> Result<(), AocError> {
+        let almanac = parse_almanac(
+            "\na-to-b map:\n10 0 5\n\nb-to-c map:\n\nc-to-d map:\n\nd-to-a map:\n0 100 5\n",
+        )?;
+        // the inverse of d-to-a would be a shorter way from a to d
+        assert_eq!(vec![12], almanac.map_value("a", "d", 2)?);
+        assert_eq!(vec![2], almanac.map_value("d", "a", 102)?);
+        Ok(())
+    }
+
+    proptest! {
+        #[test]
+        fn test_inverse_maps_contain_original_value(seed in any::<u64>(), value in 0..200u64) {
+            let generator = Generator {
+                seed_ranges: 1,
+                mappings_per_map: 4,
+                max_value: 200,
+                unmapped_chance: 0.3,
+            };
+            let input = generator.generate_static(seed);
+            let (_, maps) = input.split_once('\n').unwrap();
+            let almanac = parse_almanac(maps)?;
+            let locations = almanac.map_value("seed", "location", value)?;
+            prop_assert_eq!(1, locations.len());
+            let seeds = almanac.map_value("location", "seed", locations[0])?;
+            prop_assert!(seeds.contains(&value), "{:?}", seeds);
+        }
+
+        #[test]
+        fn test_part2_matches_reference(seed in any::<u64>()) {
+            let generator = Generator {
+                seed_ranges: 3,
+                mappings_per_map: 4,
+                max_value: 200,
+                unmapped_chance: 0.3,
+            };
+            let input = generator.generate_static(seed);
+            prop_assert_eq!(reference::part2(input)?, part2::process(input)?);
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn test_parse_almanac_never_panics(input in "[a-z\\- :0-9\n]{0,80}") {
+            let result = parse_almanac(&input);
+            prop_assert!(matches!(result, Ok(_) | Err(AocError::ParseError(_))), "{:?}", result);
+        }
+
+        #[test]
+        fn test_parse_almanac_never_panics_on_mutated_input(seed in any::<u64>()) {
+            let mut rng = Rng::new(seed);
+            let input = Generator::with_size(3).generate(&mut rng);
+            let (_, maps) = input.split_once('\n').unwrap();
+            let result = parse_almanac(&mutate(maps, &mut rng));
+            prop_assert!(matches!(result, Ok(_) | Err(AocError::ParseError(_))), "{:?}", result);
+        }
+    }
 }
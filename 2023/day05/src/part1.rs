@@ -19,13 +19,13 @@ pub fn process(input: &'static str) -> Result<u64, AocError> {
     let almanac = parse_almanac(input)?;
     let seed_locations = seeds
         .into_iter()
-        .map(|seed| almanac.location_for_seed(seed));
-    match seed_locations.len() {
-        0 => Err(AocError::LogicError(
-            "There should be one location for each seed but found 0 locations".to_string(),
-        )),
-        _ => seed_locations.min().ok_or(AocError::LogicError(
-            "Minimum should exist for vector of length > 0".to_string(),
-        )),
-    }
+        .map(|seed| almanac.map_value("seed", "location", seed))
+        .collect::<Result<Vec<_>, _>>()?;
+    seed_locations
+        .into_iter()
+        .flatten()
+        .min()
+        .ok_or(AocError::LogicError(
+            "No seed corresponds to a location".to_string(),
+        ))
 }
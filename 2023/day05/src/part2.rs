@@ -9,7 +9,7 @@ use nom::{
 
 use crate::almanac::{parse_almanac, Range};
 
-fn parse_seeds(input: &str) -> IResult<&str, Vec<Range>> {
+pub(crate) fn parse_seeds(input: &str) -> IResult<&str, Vec<Range>> {
     let (input, _) = tag("seeds: ")(input)?;
     let (input, seeds) =
         separated_list1(space1, separated_pair(u64_parser, space1, u64_parser))(input)?;
@@ -29,12 +29,13 @@ pub fn process(input: &'static str) -> Result<u64, AocError> {
             "Seeds list should contain at least one seed range".to_string(),
         ));
     }
-    let smallest_mapped_locations = seed_ranges.iter().map(|range| {
-        let mut location_ranges = almanac.location_ranges_for_seed_range(range);
-        location_ranges.sort_by_key(|range| range.start);
-        location_ranges.first().unwrap().start
-    });
-    smallest_mapped_locations.min().ok_or(AocError::LogicError(
-        "Minimum should exist for vector of length > 0".to_string(),
-    ))
+    almanac
+        .map_ranges("seed", "location", &seed_ranges)?
+        .iter()
+        .filter(|range| !range.is_empty())
+        .map(|range| range.start)
+        .min()
+        .ok_or(AocError::LogicError(
+            "No seed range corresponds to a location".to_string(),
+        ))
 }
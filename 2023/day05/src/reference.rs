@@ -0,0 +1,42 @@
+use aoclib::AocError;
+
+use crate::almanac::{parse_almanac, Almanac};
+use crate::part2::parse_seeds;
+
+/// Applies the map leaving each category to the value until it reaches the location, the maps
+/// have to form a single chain from seed to location like in the puzzle
+fn location_for_seed(almanac: &Almanac, seed: u64) -> Result<u64, AocError> {
+    let (mut category, mut value) = ("seed", seed);
+    // a chain without cycles uses every map at most once
+    for _ in 0..=almanac.maps().count() {
+        if category == "location" {
+            return Ok(value);
+        }
+        let mut maps = almanac.maps().filter(|(source, _, _)| *source == category);
+        let (Some((_, destination, map)), None) = (maps.next(), maps.next()) else {
+            return Err(AocError::LogicError(format!(
+                "Expected a single map from {}",
+                category
+            )));
+        };
+        (category, value) = (destination, map.map(value));
+    }
+    Err(AocError::LogicError(
+        "Maps from seed run in a cycle".to_string(),
+    ))
+}
+
+/// Maps every single seed of every seed range instead of mapping whole ranges, following the
+/// maps one value at a time
+pub fn part2(input: &'static str) -> Result<u64, AocError> {
+    let (input, seed_ranges) = parse_seeds(input)?;
+    let almanac = parse_almanac(input)?;
+    let seed_locations = seed_ranges
+        .iter()
+        .flat_map(|range| range.iter())
+        .map(|seed| location_for_seed(&almanac, seed))
+        .collect::<Result<Vec<_>, _>>()?;
+    seed_locations.into_iter().min().ok_or(AocError::LogicError(
+        "Seeds list should contain at least one seed".to_string(),
+    ))
+}
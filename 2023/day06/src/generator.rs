@@ -0,0 +1,51 @@
+use aoclib::generator::{InputGenerator, Rng};
+
+/// Generates race records that can be beaten
+///
+/// Keep the number of races small, part 2 concatenates all times and distances into one number
+#[derive(Debug, Clone)]
+pub struct Generator {
+    pub races: usize,
+    pub max_time: u64,
+}
+
+impl Default for Generator {
+    fn default() -> Self {
+        Self {
+            races: 4,
+            max_time: 99,
+        }
+    }
+}
+
+impl InputGenerator for Generator {
+    fn set_size(&mut self, size: usize) {
+        self.races = size;
+    }
+
+    fn generate(&self, rng: &mut Rng) -> String {
+        let records: Vec<(String, String)> = (0..self.races.max(1))
+            .map(|_| {
+                let time = 5 + rng.next_u64() % (self.max_time.max(5) - 4);
+                let best_distance = (time / 2) * (time - time / 2);
+                let distance = rng.next_u64() % best_distance;
+                (time.to_string(), distance.to_string())
+            })
+            .collect();
+        let (times, distances): (Vec<String>, Vec<String>) = records
+            .into_iter()
+            .map(|(time, distance)| {
+                let width = time.len().max(distance.len());
+                (
+                    format!("{:>width$}", time, width = width),
+                    format!("{:>width$}", distance, width = width),
+                )
+            })
+            .unzip();
+        format!(
+            "Time:      {}\nDistance:  {}\n",
+            times.join("  "),
+            distances.join("  ")
+        )
+    }
+}
@@ -1,11 +1,19 @@
+pub mod generator;
 pub mod part1;
 pub mod part2;
+#[cfg(any(test, feature = "reference"))]
+pub mod reference;
 
 #[cfg(test)]
 mod tests {
+    use crate::generator::Generator;
     use crate::part1;
     use crate::part2;
+    use crate::reference;
+    use aoclib::generator::InputGenerator;
+    use aoclib::test_generated_inputs;
     use aoclib::AocError;
+    use proptest::prelude::*;
 
     #[test]
     fn test_part1() -> Result<(), AocError> {
@@ -24,4 +32,15 @@ mod tests {
         assert_eq!(expected_output, output);
         Ok(())
     }
+
+    test_generated_inputs!(Generator::with_size(3), part1::process, part2::process);
+
+    proptest! {
+        #[test]
+        fn test_part2_matches_reference(seed in any::<u64>(), races in 1..3usize) {
+            let generator = Generator { races, max_time: 99 };
+            let input = generator.generate_static(seed);
+            prop_assert_eq!(reference::part2(input)?, part2::process(input)?);
+        }
+    }
 }
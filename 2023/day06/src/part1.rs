@@ -30,7 +30,7 @@ pub fn score_derivative(race_time: u64, time_held: u64) -> u64 {
 
 pub fn best_time_held(race_time: u64) -> (u64, Option<u64>) {
     let half = race_time / 2;
-    if race_time % 2 == 0 {
+    if race_time.is_multiple_of(2) {
         (half, None)
     } else {
         (half, Some(half + 1))
@@ -7,9 +7,9 @@ use nom::{
     sequence::preceded,
 };
 
-use crate::part1::Record;
+use crate::part1::{score_for, Record};
 
-fn parse_record(input: &str) -> Result<Record, AocError> {
+pub(crate) fn parse_record(input: &str) -> Result<Record, AocError> {
     let (input, _) = preceded(tag("Time:"), space1)(input)?;
     let (input, time_parts) = separated_list1(space1, digit1)(input)?;
     let (input, _) = newline(input)?;
@@ -42,6 +42,24 @@ fn parse_record(input: &str) -> Result<Record, AocError> {
 pub fn process(input: &'static str) -> Result<u64, AocError> {
     let record = parse_record(input)?;
     let helper = (record.time as f64).powi(2) / 4.0 - record.distance as f64;
-    let number_of_better_solutions = (helper.sqrt() * 2.0) as u64;
-    Ok(number_of_better_solutions)
+    if helper < 0.0 {
+        return Ok(0);
+    }
+    // the floating point root is only an estimate, correct it to the shortest time held that
+    // still beats the record
+    let mut shortest_time_held = (record.time as f64 / 2.0 - helper.sqrt()).max(0.0) as u64;
+    while shortest_time_held > 0 && score_for(record.time, shortest_time_held - 1) > record.distance
+    {
+        shortest_time_held -= 1;
+    }
+    while shortest_time_held <= record.time / 2
+        && score_for(record.time, shortest_time_held) <= record.distance
+    {
+        shortest_time_held += 1;
+    }
+    if shortest_time_held > record.time / 2 {
+        return Ok(0);
+    }
+    // the scores are symmetric around half of the race time
+    Ok(record.time - 2 * shortest_time_held + 1)
 }
@@ -0,0 +1,13 @@
+use aoclib::AocError;
+
+use crate::part1::score_for;
+use crate::part2::parse_record;
+
+/// Tries every possible time to hold the button instead of solving the quadratic equation
+pub fn part2(input: &'static str) -> Result<u64, AocError> {
+    let record = parse_record(input)?;
+    let better_solutions = (0..=record.time)
+        .filter(|&time_held| score_for(record.time, time_held) > record.distance)
+        .count();
+    Ok(better_solutions as u64)
+}
@@ -0,0 +1,48 @@
+use aoclib::generator::{InputGenerator, Rng};
+
+const CARDS: &[u8] = b"23456789TJQKA";
+
+/// Generates lists of five card hands with their bids
+#[derive(Debug, Clone)]
+pub struct Generator {
+    pub hands: usize,
+    pub max_bid: usize,
+}
+
+impl Default for Generator {
+    fn default() -> Self {
+        Self {
+            hands: 1000,
+            max_bid: 1000,
+        }
+    }
+}
+
+impl InputGenerator for Generator {
+    fn set_size(&mut self, size: usize) {
+        self.hands = size;
+    }
+
+    fn generate(&self, rng: &mut Rng) -> String {
+        (0..self.hands.max(1))
+            .map(|_| {
+                // favour repeated cards so that all kinds of scores show up
+                let mut hand = vec![*rng.choose(CARDS)];
+                (1..5).for_each(|_| {
+                    let card = if rng.chance(0.4) {
+                        *rng.choose(&hand)
+                    } else {
+                        *rng.choose(CARDS)
+                    };
+                    hand.push(card);
+                });
+                rng.shuffle(&mut hand);
+                format!(
+                    "{} {}\n",
+                    String::from_utf8(hand).unwrap(),
+                    rng.between(1, self.max_bid.max(1))
+                )
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,207 @@
+use std::cmp::Ordering;
+
+use aoclib::AocError;
+use nom::character::complete::{newline, satisfy, space1, u32 as u32_parser};
+use nom::multi::{count, separated_list1};
+use nom::IResult;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ScoreType {
+    HighCard,
+    OnePair,
+    TwoPairs,
+    ThreeOfAKind,
+    FullHouse,
+    FourOfAKind,
+    FiveOfAKind,
+}
+
+/// Rules of a variant of Camel Cards
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rules {
+    /// Cards from the weakest to the strongest
+    card_order: Vec<char>,
+    /// Card that stands in for whichever card makes the hand strongest
+    wildcard: Option<char>,
+    hand_size: usize,
+}
+
+/// Hand whose cards are stored as their strength under the rules it was parsed with
+///
+/// Hands order by their score type first and then card by card, which is the ranking of the
+/// game as long as both hands follow the same rules
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Hand {
+    score_type: ScoreType,
+    strengths: Vec<usize>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct HandInfo {
+    pub hand: Hand,
+    pub bid: u32,
+}
+
+impl Rules {
+    pub fn new(
+        card_order: &str,
+        wildcard: Option<char>,
+        hand_size: usize,
+    ) -> Result<Self, AocError> {
+        let card_order: Vec<char> = card_order.chars().collect();
+        if let Some((i, card)) = card_order
+            .iter()
+            .enumerate()
+            .find(|(i, card)| card_order[..*i].contains(card))
+        {
+            return Err(AocError::LogicError(format!(
+                "Card {:?} appears twice in the card order, again at position {}",
+                card, i
+            )));
+        }
+        if let Some(wildcard) = wildcard.filter(|wildcard| !card_order.contains(wildcard)) {
+            return Err(AocError::LogicError(format!(
+                "Wildcard {:?} is not part of the card order",
+                wildcard
+            )));
+        }
+        if card_order.is_empty() {
+            return Err(AocError::LogicError("There are no cards".to_string()));
+        }
+        if hand_size == 0 {
+            return Err(AocError::LogicError(
+                "Hands need at least one card".to_string(),
+            ));
+        }
+        Ok(Self {
+            card_order,
+            wildcard,
+            hand_size,
+        })
+    }
+
+    /// Five cards from 2 to A without any wildcards
+    pub fn standard() -> Self {
+        Self::new("23456789TJQKA", None, 5).expect("valid rules")
+    }
+
+    /// Five cards where the J is a joker, which is the weakest card on its own
+    pub fn jokers() -> Self {
+        Self::new("J23456789TQKA", Some('J'), 5).expect("valid rules")
+    }
+
+    pub fn hand_size(&self) -> usize {
+        self.hand_size
+    }
+
+    pub fn strength(&self, card: char) -> Option<usize> {
+        self.card_order.iter().position(|&c| c == card)
+    }
+
+    pub fn hand(&self, cards: &str) -> Result<Hand, AocError> {
+        let strengths = cards
+            .chars()
+            .map(|card| {
+                self.strength(card)
+                    .ok_or_else(|| AocError::ParseError(format!("Unknown card: {:?}", card)))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        if strengths.len() != self.hand_size {
+            return Err(AocError::ParseError(format!(
+                "Hand {} does not have {} cards",
+                cards, self.hand_size
+            )));
+        }
+        Ok(self.hand_from_strengths(strengths))
+    }
+
+    fn hand_from_strengths(&self, strengths: Vec<usize>) -> Hand {
+        Hand {
+            score_type: self.score(&strengths),
+            strengths,
+        }
+    }
+
+    /// Scores the cards, wildcards join the largest group of equal cards since that is never
+    /// worse than any other use
+    fn score(&self, strengths: &[usize]) -> ScoreType {
+        let wild = self.wildcard.and_then(|wildcard| self.strength(wildcard));
+        let mut counts = vec![0; self.card_order.len()];
+        let mut wildcards = 0;
+        strengths.iter().for_each(|&strength| {
+            if Some(strength) == wild {
+                wildcards += 1;
+            } else {
+                counts[strength] += 1;
+            }
+        });
+        counts.sort_unstable_by(|a, b| b.cmp(a));
+        counts[0] += wildcards;
+        match (counts[0], counts.get(1).copied().unwrap_or(0)) {
+            (5.., _) => ScoreType::FiveOfAKind,
+            (4, _) => ScoreType::FourOfAKind,
+            (3, 2..) => ScoreType::FullHouse,
+            (3, _) => ScoreType::ThreeOfAKind,
+            (2, 2) => ScoreType::TwoPairs,
+            (2, _) => ScoreType::OnePair,
+            _ => ScoreType::HighCard,
+        }
+    }
+
+    /// Ranks two hands of these rules, comparing them card by card if their score types tie
+    pub fn compare(&self, a: &Hand, b: &Hand) -> Ordering {
+        a.cmp(b)
+    }
+
+    fn parse_hand<'a>(&self, input: &'a str) -> IResult<&'a str, Hand> {
+        let (input, strengths) = count(
+            |input| {
+                let (input, card) = satisfy(|card| self.strength(card).is_some())(input)?;
+                Ok((input, self.strength(card).expect("satisfied")))
+            },
+            self.hand_size,
+        )(input)?;
+        Ok((input, self.hand_from_strengths(strengths)))
+    }
+
+    fn parse_hand_info<'a>(&self, input: &'a str) -> IResult<&'a str, HandInfo> {
+        let (input, hand) = self.parse_hand(input)?;
+        let (input, _) = space1(input)?;
+        let (input, bid) = u32_parser(input)?;
+        Ok((input, HandInfo { hand, bid }))
+    }
+
+    pub fn parse_hand_infos(&self, input: &str) -> Result<Vec<HandInfo>, AocError> {
+        let (input, hand_infos) =
+            separated_list1(newline, |input| self.parse_hand_info(input))(input)?;
+        let (rest, _) = newline(input)?;
+        if rest.is_empty() {
+            Ok(hand_infos)
+        } else {
+            Err(AocError::ParseError(format!(
+                "Parsing hand infos left rest: {}",
+                rest
+            )))
+        }
+    }
+
+    /// Sums the bids weighted by the rank of their hand
+    pub fn total_winnings(&self, input: &str) -> Result<u32, AocError> {
+        let mut hand_infos = self.parse_hand_infos(input)?;
+        hand_infos.sort_by(|a, b| self.compare(&a.hand, &b.hand));
+        let accum = hand_infos
+            .iter()
+            .enumerate()
+            .fold(0u32, |acc, (i, hand_info)| {
+                let rank = (i + 1) as u32;
+                acc + hand_info.bid * rank
+            });
+        Ok(accum)
+    }
+}
+
+impl Hand {
+    pub fn score_type(&self) -> ScoreType {
+        self.score_type
+    }
+}
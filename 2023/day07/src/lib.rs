@@ -1,11 +1,18 @@
+pub mod generator;
+pub mod hands;
 pub mod part1;
 pub mod part2;
 
 #[cfg(test)]
 mod tests {
+    use crate::generator::Generator;
+    use crate::hands::{Rules, ScoreType};
     use crate::part1;
     use crate::part2;
+    use aoclib::generator::{mutate, InputGenerator, Rng};
+    use aoclib::test_generated_inputs;
     use aoclib::AocError;
+    use proptest::prelude::*;
 
     #[test]
     fn test_part1() -> Result<(), AocError> {
@@ -24,4 +31,114 @@ mod tests {
         assert_eq!(expected_output, output);
         Ok(())
     }
+
+    test_generated_inputs!(Generator::with_size(50), part1::process, part2::process);
+
+    #[test]
+    fn test_score_types_with_jokers() -> Result<(), AocError> {
+        let rules = Rules::jokers();
+        [
+            ("JJJJJ", ScoreType::FiveOfAKind),
+            ("JJJJ2", ScoreType::FiveOfAKind),
+            ("JJJ23", ScoreType::FourOfAKind),
+            ("JJ234", ScoreType::ThreeOfAKind),
+            ("JJ223", ScoreType::FourOfAKind),
+            ("J2233", ScoreType::FullHouse),
+            ("J2345", ScoreType::OnePair),
+            ("22334", ScoreType::TwoPairs),
+            ("23456", ScoreType::HighCard),
+        ]
+        .into_iter()
+        .try_for_each(|(cards, expected)| {
+            assert_eq!(expected, rules.hand(cards)?.score_type(), "{}", cards);
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn test_compare_hands() -> Result<(), AocError> {
+        let standard = Rules::standard();
+        let jokers = Rules::jokers();
+        // score types decide first, then the first differing card
+        assert!(standard
+            .compare(&standard.hand("22345")?, &standard.hand("AKQT9")?)
+            .is_gt());
+        assert!(standard
+            .compare(&standard.hand("33332")?, &standard.hand("2AAAA")?)
+            .is_gt());
+        assert!(standard
+            .compare(&standard.hand("JKKK2")?, &standard.hand("QQQQ2")?)
+            .is_lt());
+        // a joker is weaker than a two when breaking ties
+        assert!(jokers
+            .compare(&jokers.hand("JKKK2")?, &jokers.hand("QQQQ2")?)
+            .is_lt());
+        assert!(jokers
+            .compare(&jokers.hand("J2222")?, &jokers.hand("22222")?)
+            .is_lt());
+        Ok(())
+    }
+
+    #[test]
+    fn test_other_rules() -> Result<(), AocError> {
+        let rules = Rules::new("*123", Some('*'), 3)?;
+        assert_eq!(ScoreType::ThreeOfAKind, rules.hand("333")?.score_type());
+        assert_eq!(ScoreType::ThreeOfAKind, rules.hand("**1")?.score_type());
+        assert_eq!(ScoreType::OnePair, rules.hand("*12")?.score_type());
+        assert_eq!(ScoreType::HighCard, rules.hand("312")?.score_type());
+        assert!(matches!(rules.hand("3124"), Err(AocError::ParseError(_))));
+        assert_eq!(
+            1 + 2 * 2 + 3 * 3,
+            rules.total_winnings("312 1\n*12 2\n1*1 3\n")?
+        );
+
+        let rules = Rules::new("23456789TJQKA", Some('2'), 7)?;
+        assert_eq!(ScoreType::FullHouse, rules.hand("AAAKKK3")?.score_type());
+        assert_eq!(ScoreType::FiveOfAKind, rules.hand("AAAA2KK")?.score_type());
+
+        assert!(Rules::new("123", Some('4'), 5).is_err());
+        assert!(Rules::new("1231", None, 5).is_err());
+        assert!(Rules::new("123", None, 0).is_err());
+        Ok(())
+    }
+
+    /// Best score type when every wildcard is replaced by some other card
+    fn best_substitution(cards: &str) -> Result<ScoreType, AocError> {
+        match cards.find('J') {
+            None => Ok(Rules::standard().hand(cards)?.score_type()),
+            Some(i) => "23456789TQKA"
+                .chars()
+                .map(|card| {
+                    let substituted = format!("{}{}{}", &cards[..i], card, &cards[i + 1..]);
+                    best_substitution(&substituted)
+                })
+                .try_fold(ScoreType::HighCard, |best, score| Ok(best.max(score?))),
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn test_jokers_take_best_substitution(cards in "[2-9TJQKA]{5}") {
+            let score = Rules::jokers().hand(&cards).unwrap().score_type();
+            prop_assert_eq!(best_substitution(&cards).unwrap(), score);
+        }
+
+        #[test]
+        fn test_parse_hand_infos_never_panics(input in "[2-9TJQKAX \n]{0,30}") {
+            let result = part1::parse_hand_infos(&input);
+            prop_assert!(matches!(result, Ok(_) | Err(AocError::ParseError(_))), "{:?}", result);
+            let result = part2::parse_hand_infos(&input);
+            prop_assert!(matches!(result, Ok(_) | Err(AocError::ParseError(_))), "{:?}", result);
+        }
+
+        #[test]
+        fn test_parse_hand_infos_never_panics_on_mutated_input(seed in any::<u64>()) {
+            let mut rng = Rng::new(seed);
+            let input = mutate(&Generator::with_size(5).generate(&mut rng), &mut rng);
+            let result = part1::parse_hand_infos(&input);
+            prop_assert!(matches!(result, Ok(_) | Err(AocError::ParseError(_))), "{:?}", result);
+            let result = part2::parse_hand_infos(&input);
+            prop_assert!(matches!(result, Ok(_) | Err(AocError::ParseError(_))), "{:?}", result);
+        }
+    }
 }
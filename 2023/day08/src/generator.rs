@@ -0,0 +1,96 @@
+use aoclib::generator::{InputGenerator, Rng};
+
+const NAME_CHARS: &[u8] = b"0123456789BCDEFGHIJKLMNOPQRSTUVWXY";
+
+/// Generates networks in which every ghost walks a loop from its start node to its end node
+///
+/// Each loop is a multiple of the directions long, so the loops are as clean as in the real
+/// input and the direction not taken at a node may point anywhere. The first ghost starts at
+/// `AAA` and ends at `ZZZ`.
+#[derive(Debug, Clone)]
+pub struct Generator {
+    pub ghosts: usize,
+    pub directions: usize,
+    /// Maximum number of times the directions are repeated along one loop
+    pub max_loop_factor: usize,
+}
+
+impl Default for Generator {
+    fn default() -> Self {
+        Self {
+            ghosts: 6,
+            directions: 20,
+            max_loop_factor: 20,
+        }
+    }
+}
+
+fn name(idx: usize, suffix: Option<u8>) -> String {
+    let base = NAME_CHARS.len();
+    let (prefix, last) = match suffix {
+        Some(suffix) => (idx, suffix),
+        None => (idx / base, NAME_CHARS[idx % base]),
+    };
+    let chars = [
+        NAME_CHARS[(prefix / base) % base],
+        NAME_CHARS[prefix % base],
+        last,
+    ];
+    String::from_utf8(chars.to_vec()).unwrap()
+}
+
+fn start_and_end_names(ghost: usize) -> (String, String) {
+    if ghost == 0 {
+        ("AAA".to_string(), "ZZZ".to_string())
+    } else {
+        (name(ghost, Some(b'A')), name(ghost, Some(b'Z')))
+    }
+}
+
+impl InputGenerator for Generator {
+    fn set_size(&mut self, size: usize) {
+        self.ghosts = size;
+    }
+
+    fn generate(&self, rng: &mut Rng) -> String {
+        let direction_count = self.directions.max(1);
+        let directions: String = (0..direction_count)
+            .map(|_| if rng.chance(0.5) { 'L' } else { 'R' })
+            .collect();
+        let max_loop_factor = self.max_loop_factor.max(1);
+        // there are only so many distinct names for the inner nodes of the loops
+        let max_ghosts = NAME_CHARS.len().pow(3) / (direction_count * max_loop_factor + 1);
+        let ghosts = self
+            .ghosts
+            .clamp(1, max_ghosts.min(NAME_CHARS.len().pow(2)));
+        let mut next_inner_idx = 0;
+        let mut nodes: Vec<(String, String, String)> = Vec::new();
+        (0..ghosts).for_each(|ghost| {
+            let (start, end) = start_and_end_names(ghost);
+            let loop_length = direction_count * rng.between(1, max_loop_factor);
+            // loop[0] is the start and loop[loop_length] the end which continues at loop[1]
+            let mut ghost_loop = vec![start];
+            ghost_loop.extend((1..loop_length).map(|_| {
+                next_inner_idx += 1;
+                name(next_inner_idx, None)
+            }));
+            ghost_loop.push(end);
+            (0..=loop_length).for_each(|step| {
+                let next = &ghost_loop[if step == loop_length { 1 } else { step + 1 }];
+                let other = rng.choose(&ghost_loop);
+                let (left, right) = if directions.as_bytes()[step % direction_count] == b'L' {
+                    (next, other)
+                } else {
+                    (other, next)
+                };
+                nodes.push((ghost_loop[step].clone(), left.clone(), right.clone()));
+            });
+        });
+        rng.shuffle(&mut nodes);
+        let nodes: String = nodes
+            .into_iter()
+            .map(|(node, left, right)| format!("{} = ({}, {})\n", node, left, right))
+            .collect();
+        format!("{}\n\n{}", directions, nodes)
+    }
+}
@@ -1,10 +1,14 @@
+pub mod generator;
 pub mod part1;
 pub mod part2;
 
 #[cfg(test)]
 mod tests {
+    use crate::generator::Generator;
     use crate::part1;
     use crate::part2;
+    use aoclib::generator::InputGenerator;
+    use aoclib::test_generated_inputs;
     use aoclib::AocError;
 
     #[test]
@@ -33,4 +37,13 @@ mod tests {
         assert_eq!(expected_output, output);
         Ok(())
     }
+
+    #[test]
+    fn test_part2_unsupported() {
+        let input = include_str!("../test_input2_unsupported.txt");
+        let output = part2::process(input);
+        assert!(matches!(output, Err(AocError::UnsupportedInput(_))));
+    }
+
+    test_generated_inputs!(Generator::with_size(3), part1::process, part2::process);
 }
@@ -1,9 +1,7 @@
-use std::collections::HashMap;
-
+use aoclib::graph::Graph;
 use aoclib::AocError;
 use nom::bytes::complete::tag;
 use nom::character::complete::{alphanumeric1, newline};
-use nom::multi::fold_many1;
 use nom::sequence::{delimited, separated_pair};
 use nom::{
     branch::alt, character::complete::char as char_parser, combinator::value, multi::many1, IResult,
@@ -15,23 +13,23 @@ pub enum Direction {
     Right,
 }
 
-#[derive(Debug)]
-pub struct Node {
-    left: String,
-    right: String,
-}
-
-impl Node {
-    pub fn new(left: String, right: String) -> Self {
-        Self { left, right }
-    }
+/// Nodes of the network, each with the edges to its left and its right node in that order
+pub type Nodes<'a> = Graph<&'a str>;
 
-    pub fn get(&self, direction: Direction) -> &str {
-        match direction {
-            Direction::Left => &self.left,
-            Direction::Right => &self.right,
-        }
-    }
+/// Returns the node reached by going into the direction from the given node
+pub fn step(nodes: &Nodes, node: usize, direction: Direction) -> Result<usize, AocError> {
+    let idx = match direction {
+        Direction::Left => 0,
+        Direction::Right => 1,
+    };
+    nodes
+        .neighbours(node)
+        .get(idx)
+        .copied()
+        .ok_or(AocError::LogicError(format!(
+            "Node {} not found",
+            nodes.label(node)
+        )))
 }
 
 fn parse_directions(input: &str) -> IResult<&str, Vec<Direction>> {
@@ -42,42 +40,44 @@ fn parse_directions(input: &str) -> IResult<&str, Vec<Direction>> {
     Ok((input, directions))
 }
 
-fn parse_node(input: &str) -> IResult<&str, (String, Node)> {
+fn parse_node(input: &str) -> IResult<&str, (&str, (&str, &str))> {
     let left_right_parser = separated_pair(alphanumeric1, tag(", "), alphanumeric1);
-    let (input, (node_name, (left, right))) = separated_pair(
+    let (input, node) = separated_pair(
         alphanumeric1,
         tag(" = "),
         delimited(char_parser('('), left_right_parser, char_parser(')')),
     )(input)?;
     let (input, _) = newline(input)?;
-    Ok((
-        input,
-        (
-            node_name.to_string(),
-            Node::new(left.to_string(), right.to_string()),
-        ),
-    ))
+    Ok((input, node))
 }
 
-fn parse_nodes(input: &str) -> IResult<&str, HashMap<String, Node>> {
-    fold_many1(parse_node, HashMap::new, |mut acc, (node_name, node)| {
-        acc.insert(node_name, node);
-        acc
-    })(input)
-}
-
-pub fn parse_input(input: &str) -> Result<(Vec<Direction>, HashMap<String, Node>), AocError> {
+pub fn parse_input(input: &str) -> Result<(Vec<Direction>, Nodes<'_>), AocError> {
     let (input, directions) = parse_directions(input)?;
     let (input, _) = newline(input)?;
-    let (rest, nodes) = parse_nodes(input)?;
-    if rest.is_empty() {
-        Ok((directions, nodes))
-    } else {
-        Err(AocError::ParseError(format!(
+    let (rest, node_lines) = many1(parse_node)(input)?;
+    if !rest.is_empty() {
+        return Err(AocError::ParseError(format!(
             "Parsing of directions and nodes left rest: {}",
             rest
-        )))
+        )));
     }
+    let mut nodes = Graph::new_directed();
+    node_lines
+        .into_iter()
+        .try_for_each(|(node_name, (left, right))| {
+            let node = nodes.add_node(node_name);
+            if !nodes.neighbours(node).is_empty() {
+                return Err(AocError::ParseError(format!(
+                    "Node {} is defined twice",
+                    node_name
+                )));
+            }
+            let (left, right) = (nodes.add_node(left), nodes.add_node(right));
+            nodes.add_edge_between(node, left);
+            nodes.add_edge_between(node, right);
+            Ok(())
+        })?;
+    Ok((directions, nodes))
 }
 
 const START_NODE: &str = "AAA";
@@ -85,16 +85,20 @@ const END_NODE: &str = "ZZZ";
 
 pub fn process(input: &'static str) -> Result<i32, AocError> {
     let (directions, nodes) = parse_input(input)?;
+    let end_node = nodes
+        .index_of(END_NODE)
+        .ok_or(AocError::LogicError(format!("Node {} not found", END_NODE)))?;
     let mut steps_taken = 0;
-    let mut current_node = START_NODE;
-    let mut direction_iter = directions.iter().cycle();
-    while current_node != END_NODE {
-        let direction = direction_iter.next().unwrap();
-        let next_node = nodes.get(current_node).ok_or(AocError::LogicError(format!(
+    let mut current_node = nodes
+        .index_of(START_NODE)
+        .ok_or(AocError::LogicError(format!(
             "Node {} not found",
-            current_node
+            START_NODE
         )))?;
-        current_node = next_node.get(*direction);
+    let mut direction_iter = directions.iter().cycle();
+    while current_node != end_node {
+        let direction = direction_iter.next().unwrap();
+        current_node = step(&nodes, current_node, *direction)?;
         steps_taken += 1;
     }
     Ok(steps_taken)
@@ -1,8 +1,10 @@
-use std::collections::HashMap;
-
+use aoclib::assumptions::Assumptions;
 use aoclib::AocError;
+use num::integer::lcm;
+
+use crate::part1::{parse_input, step, Direction, Nodes};
 
-use crate::part1::{parse_input, Direction, Node};
+type Network = (Vec<Direction>, Nodes<'static>);
 
 fn is_starting_node(node_name: &str) -> bool {
     node_name.ends_with('A')
@@ -12,87 +14,94 @@ fn is_ending_node(node_name: &str) -> bool {
     node_name.ends_with('Z')
 }
 
-fn steps_to_next_end_node<'a>(
-    start_node: &'a str,
+/// Walks from the given node until an end node is reached, continuing the directions after the
+/// given number of steps that were already taken
+fn steps_to_next_end_node(
+    start_node: usize,
+    steps_taken_before: usize,
     directions: &[Direction],
-    nodes: &'a HashMap<String, Node>,
-) -> Result<(i32, &'a str), AocError> {
+    nodes: &Nodes,
+) -> Result<(usize, usize), AocError> {
+    // after visiting every (node, direction index) combination the walk must be looping forever
+    let max_steps = directions.len() * nodes.len();
     let mut steps_taken = 0;
     let mut current_node = start_node;
-    let mut direction_iter = directions.iter().cycle();
+    let mut direction_iter = directions
+        .iter()
+        .cycle()
+        .skip(steps_taken_before % directions.len());
     loop {
         let direction = direction_iter.next().unwrap();
-        let next_node = nodes.get(current_node).ok_or(AocError::LogicError(format!(
-            "Node {} not found",
-            current_node
-        )))?;
-        current_node = next_node.get(*direction);
+        current_node = step(nodes, current_node, *direction)?;
         steps_taken += 1;
-        if is_ending_node(current_node) {
+        if is_ending_node(nodes.label(current_node)) {
             break;
         }
-    }
-    Ok((steps_taken, current_node))
-}
-
-pub fn process(input: &'static str) -> Result<i32, AocError> {
-    let (directions, nodes) = parse_input(input)?;
-    let steps_map = nodes
-        .keys()
-        .filter_map(|node_name| {
-            if is_ending_node(node_name) || is_starting_node(node_name) {
-                let start = node_name.as_str();
-                let (steps, end) =
-                    steps_to_next_end_node(start, &directions, &nodes).unwrap_or((0, start));
-                Some((start, (end, steps)))
-            } else {
-                None
-            }
-        })
-        .collect::<HashMap<_, _>>();
-    let mut current_nodes = nodes
-        .keys()
-        .filter_map(|node_name| {
-            if is_starting_node(node_name) {
-                let node_name_str = node_name.as_str();
-                Some((
-                    node_name_str,
-                    steps_to_next_end_node(node_name_str, &directions, &nodes)
-                        .unwrap_or((0, node_name_str))
-                        .0,
-                ))
-            } else {
-                None
-            }
-        })
-        .collect::<Vec<_>>();
-    let mut steps_taken = current_nodes
-        .iter()
-        .map(|(_, steps)| *steps)
-        .max()
-        .unwrap_or(0);
-    dbg!(&steps_map);
-    while !current_nodes.iter().all(|(_, steps)| *steps == steps_taken) {
-        let mut i = 0;
-        while i < current_nodes.len() {
-            let (node_name, steps) = current_nodes[i];
-            if steps == steps_taken {
-                i += 1;
-                continue;
-            }
-            let (additional_steps, new_node_name) =
-                steps_to_next_end_node(node_name, &directions, &nodes).unwrap_or((0, node_name));
-            let new_steps = steps + additional_steps;
-            current_nodes[i] = (new_node_name, new_steps);
-            steps_taken = steps_taken.max(new_steps);
-            i += 1;
-        }
-        if steps_taken > 1000000000 {
+        if steps_taken > max_steps {
             return Err(AocError::LogicError(format!(
-                "Steps taken {} is too high",
-                steps_taken
+                "No end node reachable from {}",
+                nodes.label(start_node)
             )));
         }
     }
+    Ok((steps_taken, current_node))
+}
+
+fn starting_nodes<'a>(nodes: &'a Nodes) -> impl Iterator<Item = usize> + 'a {
+    (0..nodes.len()).filter(|&node| is_starting_node(nodes.label(node)))
+}
+
+pub fn assumptions() -> Assumptions<Network> {
+    Assumptions::new()
+        .assume(
+            "every ghost reaches an end node",
+            |(directions, nodes): &Network| {
+                starting_nodes(nodes).try_for_each(|start| {
+                    steps_to_next_end_node(start, 0, directions, nodes)
+                        .map(|_| ())
+                        .map_err(|e| format!("ghost starting at {}: {}", nodes.label(start), e))
+                })
+            },
+        )
+        .assume(
+            "every ghost loops back to its first end node after as many steps as it took to reach it",
+            |(directions, nodes): &Network| {
+                starting_nodes(nodes).try_for_each(|start| {
+                    let Ok((first_steps, first_end)) =
+                        steps_to_next_end_node(start, 0, directions, nodes)
+                    else {
+                        return Ok(()); // reported by the previous assumption
+                    };
+                    let (loop_steps, loop_end) =
+                        steps_to_next_end_node(first_end, first_steps, directions, nodes)
+                            .map_err(|e| {
+                                format!("ghost starting at {}: {}", nodes.label(start), e)
+                            })?;
+                    if loop_end == first_end && loop_steps == first_steps {
+                        Ok(())
+                    } else {
+                        Err(format!(
+                            "ghost starting at {}: reaches {} after {} steps, then {} after {} more",
+                            nodes.label(start),
+                            nodes.label(first_end),
+                            first_steps,
+                            nodes.label(loop_end),
+                            loop_steps
+                        ))
+                    }
+                })
+            },
+        )
+}
+
+pub fn process(input: &'static str) -> Result<usize, AocError> {
+    let network = parse_input(input)?;
+    assumptions().check(&network)?;
+    let (directions, nodes) = network;
+    // every ghost is at an end node exactly at the multiples of its first end node steps
+    let steps_taken = starting_nodes(&nodes).try_fold(1, |acc, start| {
+        let (steps, _) = steps_to_next_end_node(start, 0, &directions, &nodes)?;
+        Ok::<usize, AocError>(lcm(acc, steps))
+    })?;
     Ok(steps_taken)
 }
@@ -0,0 +1,50 @@
+use aoclib::generator::{InputGenerator, Rng};
+
+/// Generates sequences which are the values of small integer polynomials
+#[derive(Debug, Clone)]
+pub struct Generator {
+    pub sequences: usize,
+    pub sequence_length: usize,
+    pub max_degree: usize,
+    pub max_coefficient: i64,
+}
+
+impl Default for Generator {
+    fn default() -> Self {
+        Self {
+            sequences: 200,
+            sequence_length: 21,
+            max_degree: 4,
+            max_coefficient: 5,
+        }
+    }
+}
+
+impl InputGenerator for Generator {
+    fn set_size(&mut self, size: usize) {
+        self.sequences = size;
+    }
+
+    fn generate(&self, rng: &mut Rng) -> String {
+        (0..self.sequences.max(1))
+            .map(|_| {
+                let coefficients: Vec<i64> = (0..=rng.below(self.max_degree + 1))
+                    .map(|_| {
+                        rng.between(0, 2 * self.max_coefficient as usize) as i64
+                            - self.max_coefficient
+                    })
+                    .collect();
+                let sequence: Vec<String> = (0..self.sequence_length.max(1) as i64)
+                    .map(|x| {
+                        coefficients
+                            .iter()
+                            .rev()
+                            .fold(0, |acc, coefficient| acc * x + coefficient)
+                            .to_string()
+                    })
+                    .collect();
+                sequence.join(" ") + "\n"
+            })
+            .collect()
+    }
+}
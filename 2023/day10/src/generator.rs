@@ -0,0 +1,125 @@
+use aoclib::generator::{InputGenerator, Rng};
+
+const PIPES: &[u8] = b"-|LJ7F";
+
+/// Generates square pipe fields whose main loop is the outline of a row of bars of random
+/// widths and heights, the remaining tiles are filled with random pipes
+#[derive(Debug, Clone)]
+pub struct Generator {
+    pub side_length: usize,
+    /// Probability of a tile that is not part of the main loop to contain a pipe
+    pub junk_density: f64,
+}
+
+impl Default for Generator {
+    fn default() -> Self {
+        Self {
+            side_length: 140,
+            junk_density: 0.7,
+        }
+    }
+}
+
+/// Returns the corners of the loop in walking order as (row, col)
+fn bar_outline(rng: &mut Rng, side_length: usize) -> Vec<(usize, usize)> {
+    let baseline = side_length - 2;
+    let max_height = side_length - 3;
+    let mut columns: Vec<usize> = (2..side_length - 2)
+        .filter(|_| rng.chance(0.2))
+        .chain([1, side_length - 2])
+        .collect();
+    columns.sort();
+    columns.dedup();
+    let mut heights: Vec<usize> = Vec::new();
+    (1..columns.len()).for_each(|_| {
+        let height = loop {
+            let height = rng.between(1, max_height);
+            if heights.last() != Some(&height) || max_height == 1 {
+                break height;
+            }
+        };
+        heights.push(height);
+    });
+    let mut corners = vec![
+        (baseline, columns[0]),
+        (baseline, columns[columns.len() - 1]),
+    ];
+    (0..heights.len()).rev().for_each(|bar| {
+        corners.push((baseline - heights[bar], columns[bar + 1]));
+        corners.push((baseline - heights[bar], columns[bar]));
+    });
+    corners.dedup();
+    corners
+}
+
+fn pipe_between(from: (usize, usize), at: (usize, usize), to: (usize, usize)) -> u8 {
+    let side = |other: (usize, usize)| match (other.0.cmp(&at.0), other.1.cmp(&at.1)) {
+        (std::cmp::Ordering::Less, _) => 'U',
+        (std::cmp::Ordering::Greater, _) => 'D',
+        (_, std::cmp::Ordering::Less) => 'L',
+        _ => 'R',
+    };
+    match (side(from), side(to)) {
+        ('L', 'R') | ('R', 'L') => b'-',
+        ('U', 'D') | ('D', 'U') => b'|',
+        ('L', 'U') | ('U', 'L') => b'J',
+        ('L', 'D') | ('D', 'L') => b'7',
+        ('R', 'U') | ('U', 'R') => b'L',
+        _ => b'F',
+    }
+}
+
+impl InputGenerator for Generator {
+    fn set_size(&mut self, size: usize) {
+        self.side_length = size;
+    }
+
+    fn generate(&self, rng: &mut Rng) -> String {
+        let side_length = self.side_length.max(5);
+        let corners = bar_outline(rng, side_length);
+        let mut main_loop: Vec<(usize, usize)> = Vec::new();
+        corners.iter().enumerate().for_each(|(idx, &(row, col))| {
+            let (next_row, next_col) = corners[(idx + 1) % corners.len()];
+            let mut pos = (row, col);
+            while pos != (next_row, next_col) {
+                main_loop.push(pos);
+                pos = (
+                    (pos.0 as isize + (next_row as isize - pos.0 as isize).signum()) as usize,
+                    (pos.1 as isize + (next_col as isize - pos.1 as isize).signum()) as usize,
+                );
+            }
+        });
+
+        let mut grid: Vec<Vec<u8>> = (0..side_length)
+            .map(|_| {
+                (0..side_length)
+                    .map(|_| {
+                        if rng.chance(self.junk_density) {
+                            *rng.choose(PIPES)
+                        } else {
+                            b'.'
+                        }
+                    })
+                    .collect()
+            })
+            .collect();
+        let len = main_loop.len();
+        main_loop.iter().enumerate().for_each(|(idx, &pos)| {
+            let from = main_loop[(idx + len - 1) % len];
+            let to = main_loop[(idx + 1) % len];
+            grid[pos.0][pos.1] = pipe_between(from, pos, to);
+        });
+        // the start must not look connected to any pipe outside the main loop
+        let (start_row, start_col) = main_loop[rng.below(len)];
+        grid[start_row][start_col] = b'S';
+        [(0, 1), (2, 1), (1, 0), (1, 2)]
+            .into_iter()
+            .map(|(d_row, d_col)| (start_row + d_row - 1, start_col + d_col - 1))
+            .filter(|pos| !main_loop.contains(pos))
+            .for_each(|(row, col)| grid[row][col] = b'.');
+
+        grid.into_iter()
+            .map(|row| String::from_utf8(row).unwrap() + "\n")
+            .collect()
+    }
+}
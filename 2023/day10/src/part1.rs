@@ -112,5 +112,5 @@ pub fn calculate_main_loop(grid: &Grid) -> Result<VecDeque<Location>, AocError>
 pub fn process(input: &'static str) -> Result<usize, AocError> {
     let grid = parse_grid(input)?;
     let main_loop = calculate_main_loop(&grid)?;
-    Ok((main_loop.len() + 1) / 2)
+    Ok(main_loop.len().div_ceil(2))
 }
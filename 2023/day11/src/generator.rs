@@ -0,0 +1,56 @@
+use aoclib::generator::{InputGenerator, Rng};
+
+/// Generates square images of galaxies with some empty rows and columns
+#[derive(Debug, Clone)]
+pub struct Generator {
+    pub side_length: usize,
+    pub galaxy_density: f64,
+    /// Probability of a row or column to be forced empty
+    pub empty_line_density: f64,
+}
+
+impl Default for Generator {
+    fn default() -> Self {
+        Self {
+            side_length: 140,
+            galaxy_density: 0.03,
+            empty_line_density: 0.05,
+        }
+    }
+}
+
+impl InputGenerator for Generator {
+    fn set_size(&mut self, size: usize) {
+        self.side_length = size;
+    }
+
+    fn generate(&self, rng: &mut Rng) -> String {
+        let side_length = self.side_length.max(2);
+        let empty_rows: Vec<bool> = (0..side_length)
+            .map(|_| rng.chance(self.empty_line_density))
+            .collect();
+        let empty_cols: Vec<bool> = (0..side_length)
+            .map(|_| rng.chance(self.empty_line_density))
+            .collect();
+        let mut image: Vec<Vec<u8>> = (0..side_length)
+            .map(|row| {
+                (0..side_length)
+                    .map(|col| {
+                        if !empty_rows[row] && !empty_cols[col] && rng.chance(self.galaxy_density) {
+                            b'#'
+                        } else {
+                            b'.'
+                        }
+                    })
+                    .collect()
+            })
+            .collect();
+        // there have to be at least two galaxies to measure distances
+        image[0][0] = b'#';
+        image[side_length - 1][side_length - 1] = b'#';
+        image
+            .into_iter()
+            .map(|row| String::from_utf8(row).unwrap() + "\n")
+            .collect()
+    }
+}
@@ -1,11 +1,19 @@
+pub mod generator;
 pub mod part1;
 pub mod part2;
+#[cfg(any(test, feature = "reference"))]
+pub mod reference;
 
 #[cfg(test)]
 mod tests {
+    use crate::generator::Generator;
     use crate::part1;
     use crate::part2;
+    use crate::reference;
+    use aoclib::generator::InputGenerator;
+    use aoclib::test_generated_inputs;
     use aoclib::AocError;
+    use proptest::prelude::*;
 
     #[test]
     fn test_part1() -> Result<(), AocError> {
@@ -33,4 +41,22 @@ mod tests {
         assert_eq!(expected_output, output);
         Ok(())
     }
+
+    test_generated_inputs!(Generator::with_size(20), part1::process, part2::process);
+
+    proptest! {
+        #[test]
+        fn test_expansion_matches_reference(seed in any::<u64>(), factor in 1..5usize) {
+            let generator = Generator {
+                side_length: 8,
+                galaxy_density: 0.2,
+                empty_line_density: 0.2,
+            };
+            let input = generator.generate_static(seed);
+            prop_assert_eq!(
+                reference::general_process(input, factor)?,
+                part2::general_process(input, factor)?
+            );
+        }
+    }
 }
@@ -0,0 +1,65 @@
+use aoclib::AocError;
+
+/// Expands the image by literally inserting the empty rows and columns before measuring the
+/// distances, only feasible for small expansion factors
+pub fn general_process(
+    input: &'static str,
+    galaxy_expansion_factor: usize,
+) -> Result<usize, AocError> {
+    let image: Vec<Vec<char>> = input.lines().map(|line| line.chars().collect()).collect();
+    if image.is_empty() || image.iter().any(|row| row.len() != image[0].len()) {
+        return Err(AocError::ParseError(
+            "Image should be a non-empty rectangle".to_string(),
+        ));
+    }
+    let expanded_rows: Vec<Vec<char>> = image
+        .iter()
+        .flat_map(|row| {
+            let copies = if row.contains(&'#') {
+                1
+            } else {
+                galaxy_expansion_factor
+            };
+            vec![row.clone(); copies]
+        })
+        .collect();
+    let column_copies: Vec<usize> = (0..image[0].len())
+        .map(|col| {
+            if image.iter().any(|row| row[col] == '#') {
+                1
+            } else {
+                galaxy_expansion_factor
+            }
+        })
+        .collect();
+    let expanded_image: Vec<Vec<char>> = expanded_rows
+        .into_iter()
+        .map(|row| {
+            row.into_iter()
+                .zip(&column_copies)
+                .flat_map(|(tile, &copies)| vec![tile; copies])
+                .collect()
+        })
+        .collect();
+    let galaxies: Vec<(usize, usize)> = expanded_image
+        .iter()
+        .enumerate()
+        .flat_map(|(row, tiles)| {
+            tiles
+                .iter()
+                .enumerate()
+                .filter(|(_, &tile)| tile == '#')
+                .map(move |(col, _)| (row, col))
+        })
+        .collect();
+    let distances = galaxies
+        .iter()
+        .enumerate()
+        .flat_map(|(i, &(row1, col1))| {
+            galaxies[i + 1..]
+                .iter()
+                .map(move |&(row2, col2)| row1.abs_diff(row2) + col1.abs_diff(col2))
+        })
+        .sum();
+    Ok(distances)
+}
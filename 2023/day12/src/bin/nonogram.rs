@@ -0,0 +1,20 @@
+use std::{env, fs};
+
+use aoclib::AocError;
+
+use day12::nonogram::parse_nonogram;
+
+fn main() -> Result<(), AocError> {
+    let path = env::args().nth(1).ok_or(AocError::ParseError(
+        "Usage: nonogram <clue file>".to_string(),
+    ))?;
+    let nonogram = parse_nonogram(&fs::read_to_string(path)?)?;
+    match nonogram.solve()? {
+        Some(solution) => {
+            println!("=== Nonogram ({} guesses) ===", solution.guesses);
+            print!("{}", solution.grid);
+        }
+        None => println!("The nonogram has no solution"),
+    }
+    Ok(())
+}
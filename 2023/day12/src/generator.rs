@@ -0,0 +1,60 @@
+use aoclib::generator::{InputGenerator, Rng};
+
+/// Generates condition records which have at least one arrangement
+///
+/// Each row is generated from a valid arrangement of damaged springs of which a part is replaced
+/// by unknown springs
+#[derive(Debug, Clone)]
+pub struct Generator {
+    pub rows: usize,
+    pub max_groups: usize,
+    pub max_group_size: usize,
+    /// Probability of a spring to be unknown
+    pub unknown_density: f64,
+}
+
+impl Default for Generator {
+    fn default() -> Self {
+        Self {
+            rows: 1000,
+            max_groups: 6,
+            max_group_size: 5,
+            unknown_density: 0.5,
+        }
+    }
+}
+
+impl InputGenerator for Generator {
+    fn set_size(&mut self, size: usize) {
+        self.rows = size;
+    }
+
+    fn generate(&self, rng: &mut Rng) -> String {
+        (0..self.rows.max(1))
+            .map(|_| {
+                let groups: Vec<usize> = (0..rng.between(1, self.max_groups.max(1)))
+                    .map(|_| rng.between(1, self.max_group_size.max(1)))
+                    .collect();
+                let mut springs = String::new();
+                groups.iter().enumerate().for_each(|(idx, &group)| {
+                    let min_gap = if idx == 0 { 0 } else { 1 };
+                    springs.extend((0..min_gap + rng.below(3)).map(|_| '.'));
+                    springs.extend((0..group).map(|_| '#'));
+                });
+                springs.extend((0..rng.below(3)).map(|_| '.'));
+                let springs: String = springs
+                    .chars()
+                    .map(|spring| {
+                        if rng.chance(self.unknown_density) {
+                            '?'
+                        } else {
+                            spring
+                        }
+                    })
+                    .collect();
+                let groups: Vec<String> = groups.iter().map(|group| group.to_string()).collect();
+                format!("{} {}\n", springs, groups.join(","))
+            })
+            .collect()
+    }
+}
@@ -1,11 +1,22 @@
+pub mod generator;
+pub mod nonogram;
 pub mod part1;
 pub mod part2;
+#[cfg(any(test, feature = "reference"))]
+pub mod reference;
+pub mod solutions;
 
 #[cfg(test)]
 mod tests {
-    use crate::part1;
+    use crate::generator::Generator;
+    use crate::nonogram;
+    use crate::part1::{self, SpringStatus};
     use crate::part2;
+    use crate::reference;
+    use aoclib::generator::{mutate, InputGenerator, Rng};
+    use aoclib::test_generated_inputs;
     use aoclib::AocError;
+    use proptest::prelude::*;
 
     #[test]
     fn test_part1() -> Result<(), AocError> {
@@ -76,4 +87,170 @@ mod tests {
                 Ok(())
             })
     }
+
+    fn to_string(solution: &[SpringStatus]) -> String {
+        solution.iter().map(char::from).collect()
+    }
+
+    #[test]
+    fn test_enumerate_solutions() -> Result<(), AocError> {
+        let row = part1::parse_row("?###???????? 3,2,1")?;
+        let solutions: Vec<_> = row
+            .solutions()
+            .map(|solution| to_string(&solution))
+            .collect();
+        assert_eq!(10, solutions.len());
+        assert_eq!(".###....##.#", solutions[0]);
+        assert_eq!(".###.##.#...", solutions[9]);
+        solutions.iter().enumerate().for_each(|(idx, solution)| {
+            assert_eq!(
+                Some(solution),
+                row.nth_solution(idx).map(|s| to_string(&s)).as_ref()
+            );
+        });
+        assert_eq!(None, row.nth_solution(10));
+
+        let mut rng = Rng::new(12);
+        (0..20).for_each(|_| {
+            let sample = row.sample_solution(&mut rng).map(|s| to_string(&s));
+            assert!(solutions.contains(&sample.unwrap()));
+        });
+        let unsolvable = part1::parse_row("#.# 3")?;
+        assert_eq!(0, unsolvable.solutions().len());
+        assert_eq!(None, unsolvable.sample_solution(&mut rng));
+        Ok(())
+    }
+
+    #[test]
+    fn test_nth_solution_of_unfolded_row() -> Result<(), AocError> {
+        let row = part1::parse_row("?###???????? 3,2,1")?.unfolded();
+        let count = row.count_solutions();
+        assert_eq!(506250, count);
+        [0, 1, count / 2, count - 1]
+            .into_iter()
+            .try_for_each(|idx| {
+                let solution = row.nth_solution(idx).ok_or(AocError::LogicError(format!(
+                    "No solution with index {}",
+                    idx
+                )))?;
+                assert_eq!(
+                    row.contiguous_groups,
+                    reference::contiguous_groups(&solution)
+                );
+                Ok(())
+            })
+    }
+
+    #[test]
+    fn test_forced_statuses() -> Result<(), AocError> {
+        let forced = part1::parse_row("?????? 4")?.forced_statuses();
+        assert_eq!(Some("??##??".to_string()), forced.map(|s| to_string(&s)));
+        let forced = part1::parse_row("?#??.? 2,1")?.forced_statuses();
+        assert_eq!(Some("?#??.?".to_string()), forced.map(|s| to_string(&s)));
+        assert_eq!(None, part1::parse_row("#.# 3")?.forced_statuses());
+        Ok(())
+    }
+
+    #[test]
+    fn test_nonogram() -> Result<(), AocError> {
+        let input = include_str!("../test_nonogram1.txt");
+        let solution = nonogram::parse_nonogram(input)?
+            .solve()?
+            .ok_or(AocError::LogicError("No solution found".to_string()))?;
+        assert_eq!(
+            "..#..\n.###.\n#####\n..#..\n..#..\n",
+            solution.grid.to_string()
+        );
+        assert_eq!(0, solution.guesses);
+        Ok(())
+    }
+
+    #[test]
+    fn test_nonogram_backtracking() -> Result<(), AocError> {
+        // both diagonals fit, so propagation alone gets nowhere
+        let diagonal = nonogram::parse_nonogram("1\n1\n\n1\n1\n")?;
+        let solution = diagonal
+            .solve()?
+            .ok_or(AocError::LogicError("No solution found".to_string()))?;
+        assert_eq!("#.\n.#\n", solution.grid.to_string());
+        assert_eq!(1, solution.guesses);
+
+        let letter = nonogram::parse_nonogram("3\n1,1\n5\n1,1\n1,1\n\n4\n1,1\n1,1\n1,1\n4\n")?;
+        let grid = letter
+            .solve()?
+            .ok_or(AocError::LogicError("No solution found".to_string()))?
+            .grid;
+        (0..grid.rows).for_each(|row| {
+            let cells: Vec<_> = grid.row(row).copied().collect();
+            assert_eq!(letter.rows[row], reference::contiguous_groups(&cells));
+        });
+        (0..grid.cols).for_each(|col| {
+            let cells: Vec<_> = grid.column(col).copied().collect();
+            assert_eq!(letter.columns[col], reference::contiguous_groups(&cells));
+        });
+
+        let contradicting = nonogram::parse_nonogram("2\n0\n\n0\n1\n")?;
+        assert_eq!(None, contradicting.solve()?);
+        assert!(matches!(
+            nonogram::parse_nonogram("1,0\n\n1\n"),
+            Err(AocError::ParseError(_))
+        ));
+        Ok(())
+    }
+
+    test_generated_inputs!(Generator::with_size(10), part1::process, part2::process);
+
+    proptest! {
+        #[test]
+        fn test_count_solutions_matches_reference(seed in any::<u64>()) {
+            let generator = Generator {
+                rows: 1,
+                max_groups: 4,
+                max_group_size: 3,
+                unknown_density: 0.6,
+            };
+            let input = generator.generate_with_seed(seed);
+            let row = part1::parse_row(input.trim_end())?;
+            prop_assert_eq!(row.count_solutions_by_enumeration(), row.count_solutions());
+        }
+
+        #[test]
+        fn test_solutions_match_reference(seed in any::<u64>()) {
+            let generator = Generator {
+                rows: 1,
+                max_groups: 4,
+                max_group_size: 3,
+                unknown_density: 0.6,
+            };
+            let input = generator.generate_with_seed(seed);
+            let row = part1::parse_row(input.trim_end())?;
+            let mut expected: Vec<_> = row
+                .solutions_by_enumeration()
+                .iter()
+                .map(|solution| to_string(solution))
+                .collect();
+            expected.sort();
+            let solutions: Vec<_> = row.solutions().map(|solution| to_string(&solution)).collect();
+            // '#' sorts before '.', so the solutions come in reverse order
+            prop_assert!(solutions.windows(2).all(|pair| pair[0] > pair[1]));
+            expected.reverse();
+            prop_assert_eq!(expected, solutions);
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn test_parse_row_never_panics(input in "[.#?0-9, ]{0,20}") {
+            let result = part1::parse_row(&input);
+            prop_assert!(matches!(result, Ok(_) | Err(AocError::ParseError(_))), "{:?}", result);
+        }
+
+        #[test]
+        fn test_parse_row_never_panics_on_mutated_input(seed in any::<u64>()) {
+            let mut rng = Rng::new(seed);
+            let input = Generator::with_size(1).generate(&mut rng);
+            let result = part1::parse_row(&mutate(input.trim_end(), &mut rng));
+            prop_assert!(matches!(result, Ok(_) | Err(AocError::ParseError(_))), "{:?}", result);
+        }
+    }
 }
@@ -0,0 +1,209 @@
+use std::collections::{HashSet, VecDeque};
+
+use aoclib::grid::Grid;
+use aoclib::AocError;
+use nom::{
+    character::complete::line_ending,
+    combinator::map_res,
+    multi::many1,
+    sequence::{separated_pair, terminated},
+    IResult,
+};
+
+use crate::part1::{parse_contiguous_groups, DamagedRow, SpringStatus};
+
+/// Picross puzzle given by the sizes of the groups of filled cells in every row and column
+///
+/// Filled cells are damaged springs and empty cells operational ones, so that every line is a
+/// row of springs from the puzzle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Nonogram {
+    pub rows: Vec<Vec<u32>>,
+    pub columns: Vec<Vec<u32>>,
+}
+
+/// Filled grid along with the number of cells that had to be guessed to get there
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Solution {
+    pub grid: Grid<SpringStatus>,
+    pub guesses: usize,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+enum Line {
+    Row(usize),
+    Column(usize),
+}
+
+impl DamagedRow {
+    /// Returns the status all solutions agree on for every spring, unknown for the others, or
+    /// `None` if there is no solution
+    pub fn forced_statuses(&self) -> Option<Vec<SpringStatus>> {
+        let counts = self.solution_counts();
+        let has_solutions =
+            |approach: &(usize, usize)| counts.get(approach).is_some_and(|&count| count > 0);
+        if !has_solutions(&(0, 0)) {
+            return None;
+        }
+        // follow all the indices to check that lead to solutions and collect what each of
+        // them says about the springs
+        let mut can_be_operational = vec![false; self.row.len()];
+        let mut can_be_damaged = vec![false; self.row.len()];
+        let mut checked_approaches: HashSet<(usize, usize)> = HashSet::new();
+        let mut approaches_to_check = vec![(0, 0)];
+        while let Some((row_idx, cg_idx)) = approaches_to_check.pop() {
+            if !checked_approaches.insert((row_idx, cg_idx)) {
+                continue;
+            }
+            if self.is_at_end_with_count(row_idx, cg_idx).is_some() {
+                // all springs after the last group are operational
+                can_be_operational[row_idx.min(self.row.len())..].fill(true);
+                continue;
+            }
+            if let Some(approach) = self
+                .next_to_check_if_operational(row_idx, cg_idx)
+                .filter(has_solutions)
+            {
+                can_be_operational[row_idx] = true;
+                approaches_to_check.push(approach);
+            }
+            if let Some(approach) = self
+                .next_to_check_if_damaged(row_idx, cg_idx)
+                .filter(has_solutions)
+            {
+                let group_end = row_idx + self.contiguous_groups[cg_idx] as usize;
+                can_be_damaged[row_idx..group_end].fill(true);
+                if group_end < self.row.len() {
+                    can_be_operational[group_end] = true;
+                }
+                approaches_to_check.push(approach);
+            }
+        }
+        let statuses = can_be_operational
+            .into_iter()
+            .zip(can_be_damaged)
+            .map(|statuses| match statuses {
+                (true, false) => SpringStatus::Operational,
+                (false, true) => SpringStatus::Damaged,
+                _ => SpringStatus::Unknown,
+            })
+            .collect();
+        Some(statuses)
+    }
+}
+
+impl Nonogram {
+    fn clue(&self, line: Line) -> &[u32] {
+        match line {
+            Line::Row(row) => &self.rows[row],
+            Line::Column(col) => &self.columns[col],
+        }
+    }
+
+    fn cells(grid: &Grid<SpringStatus>, line: Line) -> Vec<SpringStatus> {
+        match line {
+            Line::Row(row) => grid.row(row).copied().collect(),
+            Line::Column(col) => grid.column(col).copied().collect(),
+        }
+    }
+
+    /// Fills the cells forced by the clues of the lines, and of the lines crossing the cells
+    /// filled that way, returns `false` if a line has no solution left
+    fn propagate(&self, grid: &mut Grid<SpringStatus>, lines: Vec<Line>) -> bool {
+        let mut queued: HashSet<Line> = lines.iter().copied().collect();
+        let mut lines_to_check: VecDeque<Line> = lines.into();
+        while let Some(line) = lines_to_check.pop_front() {
+            queued.remove(&line);
+            let cells = Self::cells(grid, line);
+            let row = DamagedRow::new(cells.clone(), self.clue(line).to_vec());
+            let Some(forced) = row.forced_statuses() else {
+                return false;
+            };
+            cells
+                .iter()
+                .zip(forced)
+                .enumerate()
+                .filter(|(_, (&cell, forced))| cell != *forced)
+                .for_each(|(idx, (_, forced))| {
+                    let (row, col, crossing) = match line {
+                        Line::Row(row) => (row, idx, Line::Column(idx)),
+                        Line::Column(col) => (idx, col, Line::Row(idx)),
+                    };
+                    *grid.get_mut_unchecked(row, col) = forced;
+                    if queued.insert(crossing) {
+                        lines_to_check.push_back(crossing);
+                    }
+                });
+        }
+        true
+    }
+
+    /// Guesses the first unknown cell once propagation stalls and backtracks if that leads to
+    /// a line without solutions
+    fn backtrack(
+        &self,
+        mut grid: Grid<SpringStatus>,
+        lines: Vec<Line>,
+        guesses: &mut usize,
+    ) -> Option<Grid<SpringStatus>> {
+        if !self.propagate(&mut grid, lines) {
+            return None;
+        }
+        let Some((row, col)) = (0..grid.rows)
+            .flat_map(|row| (0..grid.cols).map(move |col| (row, col)))
+            .find(|&(row, col)| *grid.get_unchecked(row, col) == SpringStatus::Unknown)
+        else {
+            return Some(grid);
+        };
+        [SpringStatus::Damaged, SpringStatus::Operational]
+            .into_iter()
+            .find_map(|guess| {
+                *guesses += 1;
+                let mut guessed = grid.clone();
+                *guessed.get_mut_unchecked(row, col) = guess;
+                self.backtrack(guessed, vec![Line::Row(row), Line::Column(col)], guesses)
+            })
+    }
+
+    /// Finds a grid matching all clues, the first one in the order of the guesses if there are
+    /// several
+    pub fn solve(&self) -> Result<Option<Solution>, AocError> {
+        let grid = Grid::new(vec![
+            vec![SpringStatus::Unknown; self.columns.len()];
+            self.rows.len()
+        ])?;
+        let lines = (0..self.rows.len())
+            .map(Line::Row)
+            .chain((0..self.columns.len()).map(Line::Column))
+            .collect();
+        let mut guesses = 0;
+        let grid = self.backtrack(grid, lines, &mut guesses);
+        Ok(grid.map(|grid| Solution { grid, guesses }))
+    }
+}
+
+/// Parses the clues of a line, a single `0` stands for a line without filled cells
+fn parse_clue(input: &str) -> IResult<&str, Vec<u32>> {
+    map_res(parse_contiguous_groups, |clue| match clue[..] {
+        [0] => Ok(Vec::new()),
+        _ if clue.contains(&0) => Err(AocError::ParseError(
+            "Only a line without filled cells has a clue of 0".to_string(),
+        )),
+        _ => Ok(clue),
+    })(input)
+}
+
+/// Parses the clues of the rows, an empty line and the clues of the columns, with the clues of
+/// a line separated by commas like the contiguous groups of a row of springs
+pub fn parse_nonogram(input: &str) -> Result<Nonogram, AocError> {
+    let clues = || many1(terminated(parse_clue, line_ending));
+    let (rest, (rows, columns)) = separated_pair(clues(), line_ending, clues())(input)?;
+    if rest.is_empty() {
+        Ok(Nonogram { rows, columns })
+    } else {
+        Err(AocError::ParseError(format!(
+            "Parsing nonogram left rest: '{}'",
+            rest
+        )))
+    }
+}
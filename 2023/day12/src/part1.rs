@@ -17,6 +17,16 @@ pub enum SpringStatus {
     Unknown,
 }
 
+impl From<&SpringStatus> for char {
+    fn from(status: &SpringStatus) -> Self {
+        match status {
+            SpringStatus::Operational => '.',
+            SpringStatus::Damaged => '#',
+            SpringStatus::Unknown => '?',
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct DamagedRow {
     pub row: Vec<SpringStatus>,
@@ -52,7 +62,7 @@ impl DamagedRow {
     /// additionally returns a count if this is the case
     /// this count is one if solutions ending up with these indices should be counted as a solution
     /// and zero if that is not the case and the solution should be discarded as invalid
-    fn is_at_end_with_count(&self, row_idx: usize, cg_idx: usize) -> Option<usize> {
+    pub(crate) fn is_at_end_with_count(&self, row_idx: usize, cg_idx: usize) -> Option<usize> {
         let at_row_end = row_idx >= self.row.len();
         let at_cg_end = cg_idx >= self.contiguous_groups.len();
         if at_row_end && at_cg_end {
@@ -71,7 +81,11 @@ impl DamagedRow {
     }
 
     /// Returns which indices need to be checked next if the current spring is damaged
-    fn next_to_check_if_damaged(&self, row_idx: usize, cg_idx: usize) -> Option<(usize, usize)> {
+    pub(crate) fn next_to_check_if_damaged(
+        &self,
+        row_idx: usize,
+        cg_idx: usize,
+    ) -> Option<(usize, usize)> {
         match self.row[row_idx] {
             SpringStatus::Damaged | SpringStatus::Unknown => {
                 // when the spring is or might be damaged, we need to check if that is possible
@@ -90,7 +104,7 @@ impl DamagedRow {
     }
 
     /// Returns which indices need to be checked next if the current index is operational
-    fn next_to_check_if_operational(
+    pub(crate) fn next_to_check_if_operational(
         &self,
         row_idx: usize,
         cg_idx: usize,
@@ -106,7 +120,9 @@ impl DamagedRow {
         }
     }
 
-    pub fn count_solutions(&self) -> usize {
+    /// Counts the solutions from every pair of indices that is checked on the way to counting
+    /// all solutions, which are the ones for `(0, 0)`
+    pub(crate) fn solution_counts(&self) -> HashMap<(usize, usize), usize> {
         let mut counts_for_checked_approaches: HashMap<(usize, usize), usize> = HashMap::new();
         let mut approaches_to_check: VecDeque<(usize, usize)> = VecDeque::new();
         approaches_to_check.push_back((0, 0));
@@ -150,7 +166,11 @@ impl DamagedRow {
                 }
             }
         }
-        *counts_for_checked_approaches.get(&(0, 0)).unwrap()
+        counts_for_checked_approaches
+    }
+
+    pub fn count_solutions(&self) -> usize {
+        self.solution_counts()[&(0, 0)]
     }
 }
 
@@ -166,7 +186,7 @@ fn parse_row_data(input: &str) -> IResult<&str, Vec<SpringStatus>> {
     many1(status_parser)(input)
 }
 
-fn parse_contiguous_groups(input: &str) -> IResult<&str, Vec<u32>> {
+pub(crate) fn parse_contiguous_groups(input: &str) -> IResult<&str, Vec<u32>> {
     let (input, groups) = separated_list1(char_parser(','), u32_parser)(input)?;
     Ok((input, groups))
 }
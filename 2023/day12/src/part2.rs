@@ -1,4 +1,4 @@
-use std::iter::repeat;
+use std::iter::repeat_n;
 
 use aoclib::AocError;
 
@@ -13,8 +13,7 @@ impl DamagedRow {
         row[3 * self.row.len() + 3..4 * self.row.len() + 3].copy_from_slice(&self.row);
         row[4 * self.row.len() + 4..5 * self.row.len() + 4].copy_from_slice(&self.row);
 
-        let contiguous_groups = repeat(self.contiguous_groups.iter())
-            .take(5)
+        let contiguous_groups = repeat_n(self.contiguous_groups.iter(), 5)
             .flatten()
             .copied()
             .collect();
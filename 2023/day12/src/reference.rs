@@ -0,0 +1,40 @@
+use crate::part1::{DamagedRow, SpringStatus};
+
+pub fn contiguous_groups(row: &[SpringStatus]) -> Vec<u32> {
+    row.split(|&status| status == SpringStatus::Operational)
+        .filter(|group| !group.is_empty())
+        .map(|group| group.len() as u32)
+        .collect()
+}
+
+impl DamagedRow {
+    /// Finds the arrangements by trying every assignment of the unknown springs, only feasible
+    /// for rows with few unknown springs
+    pub fn solutions_by_enumeration(&self) -> Vec<Vec<SpringStatus>> {
+        let unknown_indices: Vec<usize> = self
+            .row
+            .iter()
+            .enumerate()
+            .filter(|(_, &status)| status == SpringStatus::Unknown)
+            .map(|(idx, _)| idx)
+            .collect();
+        (0..1u64 << unknown_indices.len())
+            .map(|assignment| {
+                let mut row = self.row.clone();
+                unknown_indices.iter().enumerate().for_each(|(bit, &idx)| {
+                    row[idx] = if assignment & (1 << bit) != 0 {
+                        SpringStatus::Damaged
+                    } else {
+                        SpringStatus::Operational
+                    };
+                });
+                row
+            })
+            .filter(|row| contiguous_groups(row) == self.contiguous_groups)
+            .collect()
+    }
+
+    pub fn count_solutions_by_enumeration(&self) -> usize {
+        self.solutions_by_enumeration().len()
+    }
+}
@@ -0,0 +1,104 @@
+use std::collections::HashMap;
+
+use aoclib::generator::Rng;
+
+use crate::part1::{DamagedRow, SpringStatus};
+
+/// Lazily enumerates the solutions of a row in the order of [`DamagedRow::nth_solution`]
+#[derive(Debug, Clone)]
+pub struct Solutions<'a> {
+    row: &'a DamagedRow,
+    counts: HashMap<(usize, usize), usize>,
+    next: usize,
+    count: usize,
+}
+
+impl Iterator for Solutions<'_> {
+    type Item = Vec<SpringStatus>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.next >= self.count {
+            return None;
+        }
+        self.next += 1;
+        self.row.solution_at(&self.counts, self.next - 1)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let left = self.count - self.next;
+        (left, Some(left))
+    }
+}
+
+impl ExactSizeIterator for Solutions<'_> {}
+
+impl DamagedRow {
+    /// Follows the indices to check that lead to the solution with the given index, skipping
+    /// all solutions with an operational spring before those with a damaged one by their count
+    fn solution_at(
+        &self,
+        counts: &HashMap<(usize, usize), usize>,
+        mut idx: usize,
+    ) -> Option<Vec<SpringStatus>> {
+        if idx >= counts.get(&(0, 0)).copied().unwrap_or(0) {
+            return None;
+        }
+        let count_of = |approach: Option<(usize, usize)>| {
+            approach
+                .and_then(|approach| counts.get(&approach).copied())
+                .unwrap_or(0)
+        };
+        let mut solution = self.row.clone();
+        let (mut row_idx, mut cg_idx) = (0, 0);
+        while self.is_at_end_with_count(row_idx, cg_idx).is_none() {
+            let if_operational = self.next_to_check_if_operational(row_idx, cg_idx);
+            let count_if_operational = count_of(if_operational);
+            match if_operational {
+                Some(approach) if idx < count_if_operational => {
+                    solution[row_idx] = SpringStatus::Operational;
+                    (row_idx, cg_idx) = approach;
+                }
+                _ => {
+                    idx -= count_if_operational;
+                    let approach = self.next_to_check_if_damaged(row_idx, cg_idx)?;
+                    let group_end = row_idx + self.contiguous_groups[cg_idx] as usize;
+                    solution[row_idx..group_end].fill(SpringStatus::Damaged);
+                    if group_end < solution.len() {
+                        solution[group_end] = SpringStatus::Operational;
+                    }
+                    (row_idx, cg_idx) = approach;
+                }
+            }
+        }
+        // all springs after the last group are operational
+        let rest = row_idx.min(solution.len());
+        solution[rest..].fill(SpringStatus::Operational);
+        Some(solution)
+    }
+
+    pub fn solutions(&self) -> Solutions<'_> {
+        let counts = self.solution_counts();
+        let count = counts[&(0, 0)];
+        Solutions {
+            row: self,
+            counts,
+            next: 0,
+            count,
+        }
+    }
+
+    /// Returns the solution with the given index, solutions with an operational spring come
+    /// before those with a damaged one at the first spring they differ in
+    pub fn nth_solution(&self, idx: usize) -> Option<Vec<SpringStatus>> {
+        self.solution_at(&self.solution_counts(), idx)
+    }
+
+    /// Picks one of the solutions uniformly at random
+    pub fn sample_solution(&self, rng: &mut Rng) -> Option<Vec<SpringStatus>> {
+        let counts = self.solution_counts();
+        match counts[&(0, 0)] {
+            0 => None,
+            count => self.solution_at(&counts, rng.below(count)),
+        }
+    }
+}
@@ -0,0 +1,69 @@
+use aoclib::generator::{InputGenerator, Rng};
+
+/// Generates patterns of ash and rocks which each have a guaranteed mirror axis
+#[derive(Debug, Clone)]
+pub struct Generator {
+    pub patterns: usize,
+    pub min_side_length: usize,
+    pub max_side_length: usize,
+}
+
+impl Default for Generator {
+    fn default() -> Self {
+        Self {
+            patterns: 100,
+            min_side_length: 5,
+            max_side_length: 17,
+        }
+    }
+}
+
+/// Mirrors the lines of one side of the axis between `idx` and `idx + 1` onto the other side
+fn mirror<T: Clone>(lines: &mut [T], idx: usize) {
+    let len = lines.len();
+    (0..=idx)
+        .rev()
+        .zip(idx + 1..len)
+        .for_each(|(before, after)| lines[after] = lines[before].clone());
+}
+
+fn generate_pattern(rng: &mut Rng, rows: usize, cols: usize) -> Vec<Vec<bool>> {
+    let mut pattern: Vec<Vec<bool>> = (0..rows)
+        .map(|_| (0..cols).map(|_| rng.chance(0.5)).collect())
+        .collect();
+    if rng.chance(0.5) {
+        let idx = rng.below(rows - 1);
+        mirror(&mut pattern, idx);
+    } else {
+        let idx = rng.below(cols - 1);
+        pattern.iter_mut().for_each(|row| mirror(row, idx));
+    }
+    pattern
+}
+
+impl InputGenerator for Generator {
+    fn set_size(&mut self, size: usize) {
+        self.patterns = size;
+    }
+
+    fn generate(&self, rng: &mut Rng) -> String {
+        let min_side_length = self.min_side_length.max(2);
+        let max_side_length = self.max_side_length.max(min_side_length);
+        let patterns: Vec<String> = (0..self.patterns.max(1))
+            .map(|_| {
+                let rows = rng.between(min_side_length, max_side_length);
+                let cols = rng.between(min_side_length, max_side_length);
+                generate_pattern(rng, rows, cols)
+                    .into_iter()
+                    .map(|row| {
+                        row.into_iter()
+                            .map(|rock| if rock { '#' } else { '.' })
+                            .collect::<String>()
+                            + "\n"
+                    })
+                    .collect()
+            })
+            .collect();
+        patterns.join("\n")
+    }
+}
@@ -1,10 +1,17 @@
+pub mod generator;
+pub mod mirrors;
 pub mod part1;
 pub mod part2;
 
 #[cfg(test)]
 mod tests {
-    use crate::part1;
+    use crate::generator::Generator;
+    use crate::mirrors::{find_reflections, Reflection};
+    use crate::part1::{self, MirrorAxis, Terrain};
     use crate::part2;
+    use aoclib::generator::{check_generated_inputs, InputGenerator};
+    use aoclib::position::Position;
+    use aoclib::test_generated_inputs;
     use aoclib::AocError;
 
     #[test]
@@ -34,4 +41,65 @@ mod tests {
         assert_eq!(expected_output, output);
         Ok(())
     }
+
+    #[test]
+    fn test_find_reflections() -> Result<(), AocError> {
+        let terrains = part1::parse_all(include_str!("../test_input1.txt"))?;
+        let axes = |terrain: &Terrain, smudges: usize| -> Vec<MirrorAxis> {
+            find_reflections(terrain, smudges)
+                .into_iter()
+                .map(|reflection| reflection.axis)
+                .collect()
+        };
+        assert_eq!(vec![MirrorAxis::Vertical(4)], axes(&terrains[0], 0));
+        assert_eq!(vec![MirrorAxis::Horizontal(3)], axes(&terrains[1], 0));
+        assert_eq!(
+            vec![Reflection {
+                axis: MirrorAxis::Horizontal(2),
+                mismatches: vec![(Position::new(0, 0), Position::new(5, 0))],
+            }],
+            find_reflections(&terrains[0], 1)
+        );
+        assert_eq!(
+            vec![Reflection {
+                axis: MirrorAxis::Horizontal(0),
+                mismatches: vec![(Position::new(0, 4), Position::new(1, 4))],
+            }],
+            find_reflections(&terrains[1], 1)
+        );
+        find_reflections(&terrains[0], 3)
+            .iter()
+            .for_each(|reflection| assert_eq!(3, reflection.mismatches.len()));
+        Ok(())
+    }
+
+    #[test]
+    fn test_fixing_smudges_mirrors_terrain() -> Result<(), AocError> {
+        check_generated_inputs(&Generator::with_size(5), |input| {
+            let terrains = part1::parse_all(input)?;
+            terrains.iter().for_each(|terrain| {
+                (1..=2).for_each(|smudges| {
+                    find_reflections(terrain, smudges)
+                        .into_iter()
+                        .for_each(|reflection| {
+                            let mut rows: Vec<Vec<bool>> = terrain
+                                .rows
+                                .iter()
+                                .map(|row| row.iter().by_vals().collect())
+                                .collect();
+                            reflection.mismatches.iter().for_each(|(before, _)| {
+                                rows[before.row][before.col] = !rows[before.row][before.col]
+                            });
+                            let fixed = Terrain::new(rows);
+                            assert!(find_reflections(&fixed, 0)
+                                .iter()
+                                .any(|fixed_reflection| fixed_reflection.axis == reflection.axis));
+                        })
+                })
+            });
+            Ok(())
+        })
+    }
+
+    test_generated_inputs!(Generator::with_size(5), part1::process, part2::process);
 }
@@ -0,0 +1,66 @@
+use aoclib::position::Position;
+use bitvec::{slice::BitSlice, vec::BitVec};
+
+use crate::part1::{MirrorAxis, Terrain};
+
+/// Axis the terrain mirrors across, up to the pairs of cells that differ from their mirror
+/// image, each given by the cell before the axis and the one after it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Reflection {
+    pub axis: MirrorAxis,
+    pub mismatches: Vec<(Position, Position)>,
+}
+
+/// Positions at which the two lines differ, compared bit by bit instead of allocating their XOR
+fn differences<'a>(first: &'a BitSlice, second: &'a BitSlice) -> impl Iterator<Item = usize> + 'a {
+    first
+        .iter()
+        .by_vals()
+        .zip(second.iter().by_vals())
+        .enumerate()
+        .filter(|(_, (first, second))| first != second)
+        .map(|(idx, _)| idx)
+}
+
+/// Finds every index `idx` such that mirroring the lines across the axis between `idx` and
+/// `idx + 1` changes exactly `smudges` bits, along with the positions of the differing bits
+/// before and after the axis, given by their line and bit index
+fn line_reflections(
+    lines: &[BitVec],
+    smudges: usize,
+    position: impl Fn(usize, usize) -> Position,
+) -> Vec<(usize, Vec<(Position, Position)>)> {
+    let len = lines.len();
+    (0..len.saturating_sub(1))
+        .filter_map(|idx| {
+            let mut mismatches = Vec::new();
+            for (before, after) in (0..=idx).rev().zip(idx + 1..len) {
+                for bit in differences(&lines[before], &lines[after]) {
+                    if mismatches.len() == smudges {
+                        return None;
+                    }
+                    mismatches.push((position(before, bit), position(after, bit)));
+                }
+            }
+            (mismatches.len() == smudges).then_some((idx, mismatches))
+        })
+        .collect()
+}
+
+/// Finds all axes across which the terrain mirrors with exactly `smudges` cells differing from
+/// their mirror image, vertical axes first
+pub fn find_reflections(terrain: &Terrain, smudges: usize) -> Vec<Reflection> {
+    let vertical = line_reflections(&terrain.cols, smudges, |col, row| Position::new(row, col))
+        .into_iter()
+        .map(|(idx, mismatches)| Reflection {
+            axis: MirrorAxis::Vertical(idx),
+            mismatches,
+        });
+    let horizontal = line_reflections(&terrain.rows, smudges, Position::new)
+        .into_iter()
+        .map(|(idx, mismatches)| Reflection {
+            axis: MirrorAxis::Horizontal(idx),
+            mismatches,
+        });
+    vertical.chain(horizontal).collect()
+}
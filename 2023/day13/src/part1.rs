@@ -9,6 +9,8 @@ use nom::{
     IResult,
 };
 
+use crate::mirrors::find_reflections;
+
 #[derive(Debug)]
 pub struct Terrain {
     pub rows: Vec<BitVec>,
@@ -64,31 +66,28 @@ pub fn parse_all(input: &str) -> Result<Vec<Terrain>, AocError> {
     }
 }
 
+/// Axis between the line with the given index and the next one
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum MirrorAxis {
     Horizontal(usize),
     Vertical(usize),
 }
 
-pub fn find_mirror_index(bitvecs: &[BitVec]) -> Option<usize> {
-    let len = bitvecs.len();
-    (0..len - 1).find(|idx| {
-        let before_indices = (0..=*idx).rev();
-        let after_indices = (*idx + 1)..len;
-        for (before_idx, after_idx) in before_indices.zip(after_indices) {
-            println!("before_idx: {}, after_idx: {}", before_idx, after_idx);
-            if bitvecs[before_idx] != bitvecs[after_idx] {
-                return false;
-            }
+impl MirrorAxis {
+    /// Number of columns left of a vertical axis, or a hundred times the number of rows above a
+    /// horizontal one
+    pub fn summary(&self) -> usize {
+        match *self {
+            Self::Horizontal(idx) => 100 * (idx + 1),
+            Self::Vertical(idx) => idx + 1,
         }
-        true
-    })
+    }
 }
 
 pub fn find_mirror_axis(terrain: &Terrain) -> Option<MirrorAxis> {
-    find_mirror_index(&terrain.rows)
-        .map(MirrorAxis::Horizontal)
-        .or_else(|| find_mirror_index(&terrain.cols).map(MirrorAxis::Vertical))
+    find_reflections(terrain, 0)
+        .first()
+        .map(|reflection| reflection.axis)
 }
 
 pub fn process(input: &'static str) -> Result<usize, AocError> {
@@ -96,11 +95,7 @@ pub fn process(input: &'static str) -> Result<usize, AocError> {
     let score = terrains
         .iter()
         .filter_map(find_mirror_axis)
-        .fold(0usize, |acc, axis| {
-            acc + match axis {
-                MirrorAxis::Horizontal(idx) => 100 * (idx + 1),
-                MirrorAxis::Vertical(idx) => idx + 1,
-            }
-        });
+        .map(|axis| axis.summary())
+        .sum();
     Ok(score)
 }
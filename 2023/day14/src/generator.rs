@@ -0,0 +1,45 @@
+use aoclib::generator::{InputGenerator, Rng};
+
+/// Generates square platforms of rounded and cube-shaped rocks
+#[derive(Debug, Clone)]
+pub struct Generator {
+    pub side_length: usize,
+    pub rolling_density: f64,
+    pub stationary_density: f64,
+}
+
+impl Default for Generator {
+    fn default() -> Self {
+        Self {
+            side_length: 100,
+            rolling_density: 0.2,
+            stationary_density: 0.1,
+        }
+    }
+}
+
+impl InputGenerator for Generator {
+    fn set_size(&mut self, size: usize) {
+        self.side_length = size;
+    }
+
+    fn generate(&self, rng: &mut Rng) -> String {
+        let side_length = self.side_length.max(1);
+        (0..side_length)
+            .map(|_| {
+                (0..side_length)
+                    .map(|_| {
+                        if rng.chance(self.rolling_density) {
+                            'O'
+                        } else if rng.chance(self.stationary_density) {
+                            '#'
+                        } else {
+                            '.'
+                        }
+                    })
+                    .collect::<String>()
+                    + "\n"
+            })
+            .collect()
+    }
+}
@@ -1,10 +1,17 @@
+pub mod generator;
 pub mod part1;
 pub mod part2;
+pub mod simulator;
 
 #[cfg(test)]
 mod tests {
+    use crate::generator::Generator;
     use crate::part1;
     use crate::part2;
+    use crate::simulator::{load, parse_tilts, tilt, Simulation};
+    use aoclib::direction::Direction;
+    use aoclib::generator::{check_generated_inputs, InputGenerator};
+    use aoclib::test_generated_inputs;
     use aoclib::AocError;
 
     #[test]
@@ -34,4 +41,73 @@ mod tests {
         assert_eq!(expected_output, output);
         Ok(())
     }
+
+    #[test]
+    fn test_spin_cycle_snapshots() -> Result<(), AocError> {
+        let grid = part1::parse_grid(include_str!("../test_input1.txt"))?;
+        let simulation = Simulation::new(grid, &parse_tilts("NWSE")?);
+        let snapshots: Vec<_> = simulation
+            .snapshots(&[1, 2, 3])
+            .into_iter()
+            .map(|grid| grid.to_string())
+            .collect();
+        let expected = [
+            ".....#....\n....#...O#\n...OO##...\n.OO#......\n.....OOO#.\n.O#...O#.#\n....O#....\n......OOOO\n#...O###..\n#..OO#....\n",
+            ".....#....\n....#...O#\n.....##...\n..O#......\n.....OOO#.\n.O#...O#.#\n....O#...O\n.......OOO\n#..OO###..\n#.OOO#...O\n",
+            ".....#....\n....#...O#\n.....##...\n..O#......\n.....OOO#.\n.O#...O#.#\n....O#...O\n.......OOO\n#...O###.O\n#.OOO#...O\n",
+        ];
+        assert_eq!(expected.to_vec(), snapshots);
+        assert_eq!(3, simulation.cycle_start());
+        assert_eq!(7, simulation.cycle_length());
+        assert_eq!(simulation.after(3), simulation.after(3 + 7 * 1000));
+        Ok(())
+    }
+
+    #[test]
+    fn test_tilt_sequences() -> Result<(), AocError> {
+        let grid = part1::parse_grid(include_str!("../test_input1.txt"))?;
+        // tilting north twice is the same as tilting it once
+        let simulation = Simulation::new(grid.clone(), &parse_tilts("NN")?);
+        assert_eq!(1, simulation.cycle_start());
+        assert_eq!(1, simulation.cycle_length());
+        assert_eq!(136, load(simulation.after(5), Direction::Up));
+        // not tilting at all keeps the grid as it is
+        let simulation = Simulation::new(grid.clone(), &[]);
+        assert_eq!(&grid, simulation.after(10));
+
+        // rocks only ever roll towards the edge the dish is tilted to
+        Direction::each().for_each(|direction| {
+            let mut tilted = grid.clone();
+            tilt(&mut tilted, direction);
+            assert!(load(&tilted, direction) > load(&grid, direction));
+            assert!(load(&tilted, direction.opposite()) < load(&grid, direction.opposite()));
+        });
+        assert!(matches!(parse_tilts("NX"), Err(AocError::ParseError(_))));
+        Ok(())
+    }
+
+    #[test]
+    fn test_opposite_loads() -> Result<(), AocError> {
+        check_generated_inputs(&Generator::with_size(12), |input| {
+            let grid = part1::parse_grid(input)?;
+            let rounded: usize = (0..grid.rows)
+                .map(|row| {
+                    grid.row(row)
+                        .filter(|&&rock| rock == part1::Rock::Rolling)
+                        .count()
+                })
+                .sum();
+            assert_eq!(
+                rounded * (grid.rows + 1),
+                load(&grid, Direction::Up) + load(&grid, Direction::Down)
+            );
+            assert_eq!(
+                rounded * (grid.cols + 1),
+                load(&grid, Direction::Left) + load(&grid, Direction::Right)
+            );
+            Ok(())
+        })
+    }
+
+    test_generated_inputs!(Generator::with_size(12), part1::process, part2::process);
 }
@@ -0,0 +1,121 @@
+use std::collections::{HashMap, VecDeque};
+use std::mem::swap;
+
+use aoclib::direction::Direction;
+use aoclib::grid::Grid;
+use aoclib::AocError;
+
+use crate::part1::Rock;
+
+/// Rolls the rounded rocks towards the start of the iterator until they hit a cube-shaped rock
+fn tilt_line<'a>(iter: impl Iterator<Item = &'a mut Rock>) {
+    let mut swappable_empty_rocks: VecDeque<&mut Rock> = VecDeque::new();
+    for rock in iter {
+        match *rock {
+            Rock::Empty => swappable_empty_rocks.push_back(rock),
+            Rock::Stationary => swappable_empty_rocks.clear(),
+            Rock::Rolling => {
+                let swap_partner = swappable_empty_rocks.pop_front();
+                if let Some(other_rock) = swap_partner {
+                    swap(rock, other_rock);
+                    swappable_empty_rocks.push_back(rock);
+                }
+            }
+        }
+    }
+}
+
+/// Tilts the dish so that all rounded rocks roll towards the edge in the given direction
+pub fn tilt(grid: &mut Grid<Rock>, direction: Direction) {
+    match direction {
+        Direction::Up => (0..grid.cols).for_each(|col| tilt_line(grid.column_mut(col))),
+        Direction::Down => (0..grid.cols).for_each(|col| tilt_line(grid.column_mut(col).rev())),
+        Direction::Left => (0..grid.rows).for_each(|row| tilt_line(grid.row_mut(row))),
+        Direction::Right => (0..grid.rows).for_each(|row| tilt_line(grid.row_mut(row).rev())),
+    }
+}
+
+/// Sums up the distances of the rounded rocks to the opposite edge, counting the line at that
+/// edge as one
+pub fn load(grid: &Grid<Rock>, edge: Direction) -> usize {
+    (0..grid.rows)
+        .flat_map(|row| (0..grid.cols).map(move |col| (row, col)))
+        .filter(|&(row, col)| *grid.get_unchecked(row, col) == Rock::Rolling)
+        .map(|(row, col)| match edge {
+            Direction::Up => grid.rows - row,
+            Direction::Down => row + 1,
+            Direction::Left => grid.cols - col,
+            Direction::Right => col + 1,
+        })
+        .sum()
+}
+
+/// Parses a sequence of tilts given by the compass directions `N`, `W`, `S` and `E`
+pub fn parse_tilts(input: &str) -> Result<Vec<Direction>, AocError> {
+    input
+        .chars()
+        .map(|direction| match direction {
+            'N' => Ok(Direction::Up),
+            'W' => Ok(Direction::Left),
+            'S' => Ok(Direction::Down),
+            'E' => Ok(Direction::Right),
+            _ => Err(AocError::ParseError(format!(
+                "Unknown tilt direction: {}",
+                direction
+            ))),
+        })
+        .collect()
+}
+
+/// All grids a dish goes through when applying a sequence of tilts over and over, each step
+/// applies the whole sequence
+#[derive(Debug, Clone)]
+pub struct Simulation {
+    /// Grid before the first step and after every step until a grid repeats
+    grids: Vec<Grid<Rock>>,
+    /// Step after which the grid repeats for the first time
+    cycle_start: usize,
+}
+
+impl Simulation {
+    /// Applies the tilts until a grid repeats, which it has to as there are only finitely many
+    /// ways to place the rounded rocks
+    pub fn new(grid: Grid<Rock>, tilts: &[Direction]) -> Self {
+        let mut seen_grids: HashMap<Grid<Rock>, usize> = HashMap::from([(grid.clone(), 0)]);
+        let mut grids = vec![grid];
+        loop {
+            let mut next = grids[grids.len() - 1].clone();
+            tilts
+                .iter()
+                .for_each(|&direction| tilt(&mut next, direction));
+            if let Some(&cycle_start) = seen_grids.get(&next) {
+                return Self { grids, cycle_start };
+            }
+            seen_grids.insert(next.clone(), grids.len());
+            grids.push(next);
+        }
+    }
+
+    pub fn cycle_start(&self) -> usize {
+        self.cycle_start
+    }
+
+    /// Number of steps after which the grids repeat from the start of the cycle on
+    pub fn cycle_length(&self) -> usize {
+        self.grids.len() - self.cycle_start
+    }
+
+    /// Grid after the given number of steps, fast-forwarding through the repetitions
+    pub fn after(&self, steps: usize) -> &Grid<Rock> {
+        if steps < self.grids.len() {
+            &self.grids[steps]
+        } else {
+            let offset = (steps - self.cycle_start) % self.cycle_length();
+            &self.grids[self.cycle_start + offset]
+        }
+    }
+
+    pub fn snapshots(&self, steps: &[usize]) -> Vec<&Grid<Rock>> {
+        steps.iter().map(|&steps| self.after(steps)).collect()
+    }
+}
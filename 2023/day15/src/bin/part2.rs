@@ -1,9 +1,16 @@
+use std::env;
+
 use aoclib::AocError;
 
 use day15::part2::process;
 
 fn main() -> Result<(), AocError> {
     let input = include_str!("../../input.txt");
+    if env::args().any(|arg| arg == "--trace") {
+        let entries = day15::part1::parse_input(input)?;
+        print!("{}", day15::library::walkthrough(&entries));
+        return Ok(());
+    }
     let output = process(input)?;
     println!("=== Part 2 Result ===");
     println!("{}", output);
@@ -0,0 +1,49 @@
+use aoclib::generator::{InputGenerator, Rng};
+
+/// Generates initialization sequences of insert and remove steps
+#[derive(Debug, Clone)]
+pub struct Generator {
+    pub steps: usize,
+    /// Number of distinct labels used by the steps, smaller values lead to more replacements
+    pub labels: usize,
+    pub max_label_length: usize,
+    pub remove_probability: f64,
+}
+
+impl Default for Generator {
+    fn default() -> Self {
+        Self {
+            steps: 4000,
+            labels: 500,
+            max_label_length: 6,
+            remove_probability: 0.3,
+        }
+    }
+}
+
+impl InputGenerator for Generator {
+    fn set_size(&mut self, size: usize) {
+        self.steps = size;
+    }
+
+    fn generate(&self, rng: &mut Rng) -> String {
+        let labels: Vec<String> = (0..self.labels.max(1))
+            .map(|_| {
+                (0..rng.between(1, self.max_label_length.max(1)))
+                    .map(|_| char::from(b'a' + rng.below(26) as u8))
+                    .collect()
+            })
+            .collect();
+        let steps: Vec<String> = (0..self.steps.max(1))
+            .map(|_| {
+                let label = rng.choose(&labels);
+                if rng.chance(self.remove_probability) {
+                    format!("{}-", label)
+                } else {
+                    format!("{}={}", label, rng.between(1, 9))
+                }
+            })
+            .collect();
+        steps.join(",") + "\n"
+    }
+}
@@ -1,10 +1,16 @@
+pub mod generator;
+pub mod library;
 pub mod part1;
 pub mod part2;
 
 #[cfg(test)]
 mod tests {
+    use crate::generator::Generator;
+    use crate::library::{walkthrough, Change, Lens, LensLibrary};
     use crate::part1;
     use crate::part2;
+    use aoclib::generator::{check_generated_inputs, InputGenerator};
+    use aoclib::test_generated_inputs;
     use aoclib::AocError;
 
     #[test]
@@ -34,4 +40,144 @@ mod tests {
         assert_eq!(expected_output, output);
         Ok(())
     }
+
+    #[test]
+    fn test_walkthrough() -> Result<(), AocError> {
+        let entries = part1::parse_input(include_str!("../test_input1.txt"))?;
+        let expected = r#"After "rn=1":
+Box 0: [rn 1]
+
+After "cm-":
+Box 0: [rn 1]
+
+After "qp=3":
+Box 0: [rn 1]
+Box 1: [qp 3]
+
+After "cm=2":
+Box 0: [rn 1] [cm 2]
+Box 1: [qp 3]
+
+After "qp-":
+Box 0: [rn 1] [cm 2]
+
+After "pc=4":
+Box 0: [rn 1] [cm 2]
+Box 3: [pc 4]
+
+After "ot=9":
+Box 0: [rn 1] [cm 2]
+Box 3: [pc 4] [ot 9]
+
+After "ab=5":
+Box 0: [rn 1] [cm 2]
+Box 3: [pc 4] [ot 9] [ab 5]
+
+After "pc-":
+Box 0: [rn 1] [cm 2]
+Box 3: [ot 9] [ab 5]
+
+After "pc=6":
+Box 0: [rn 1] [cm 2]
+Box 3: [ot 9] [ab 5] [pc 6]
+
+After "ot=7":
+Box 0: [rn 1] [cm 2]
+Box 3: [ot 7] [ab 5] [pc 6]
+
+"#;
+        assert_eq!(expected, walkthrough(&entries));
+        Ok(())
+    }
+
+    #[test]
+    fn test_lens_library_trace() -> Result<(), AocError> {
+        let entries = part1::parse_input(include_str!("../test_input1.txt"))?;
+        let mut library = LensLibrary::new();
+        entries.iter().for_each(|entry| {
+            library.apply(entry);
+        });
+        let changes: Vec<_> = library
+            .trace()
+            .iter()
+            .map(|step| (step.box_num, step.change))
+            .collect();
+        let lens = |label, focal_length| Lens {
+            label,
+            focal_length,
+        };
+        assert_eq!(
+            vec![
+                (0, Change::Insert(lens("rn", 1))),
+                (0, Change::Unchanged),
+                (1, Change::Insert(lens("qp", 3))),
+                (0, Change::Insert(lens("cm", 2))),
+                (
+                    1,
+                    Change::Remove {
+                        slot: 0,
+                        lens: lens("qp", 3)
+                    }
+                ),
+                (3, Change::Insert(lens("pc", 4))),
+                (3, Change::Insert(lens("ot", 9))),
+                (3, Change::Insert(lens("ab", 5))),
+                (
+                    3,
+                    Change::Remove {
+                        slot: 0,
+                        lens: lens("pc", 4)
+                    }
+                ),
+                (3, Change::Insert(lens("pc", 6))),
+                (
+                    3,
+                    Change::Replace {
+                        slot: 0,
+                        previous: lens("ot", 9)
+                    }
+                ),
+            ],
+            changes
+        );
+        assert_eq!(145, library.focusing_power());
+        assert_eq!(
+            vec![(0, 2), (3, 3)],
+            library
+                .boxes()
+                .map(|(box_num, lenses)| (box_num, lenses.len()))
+                .collect::<Vec<_>>()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_undo_restores_snapshots() -> Result<(), AocError> {
+        check_generated_inputs(&Generator::with_size(50), |input| {
+            let entries = part1::parse_input(input)?;
+            let mut library = LensLibrary::new();
+            let snapshots: Vec<_> = entries
+                .iter()
+                .map(|entry| {
+                    let snapshot = library.clone();
+                    library.apply(entry);
+                    snapshot
+                })
+                .collect();
+            snapshots
+                .into_iter()
+                .rev()
+                .zip(entries.iter().rev())
+                .for_each(|(snapshot, entry)| {
+                    let step = library.undo();
+                    assert_eq!(Some(entry), step.as_ref().map(|step| &step.entry));
+                    assert_eq!(snapshot, library);
+                });
+            assert_eq!(None, library.undo());
+            assert_eq!("", library.snapshot());
+            Ok(())
+        })
+    }
+
+    test_generated_inputs!(Generator::with_size(50), part1::process, part2::process);
 }
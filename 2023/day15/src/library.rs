@@ -0,0 +1,171 @@
+use std::fmt;
+
+use crate::part1::{char_hash, Entry, EntryEnding};
+
+const BOXES: usize = 256;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Lens {
+    pub label: &'static str,
+    pub focal_length: u8,
+}
+
+impl fmt::Display for Lens {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[{} {}]", self.label, self.focal_length)
+    }
+}
+
+/// What applying an entry did to the lenses in its box
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Change {
+    /// Put the lens behind all the lenses in the box
+    Insert(Lens),
+    /// Put the lens into the slot of the previous one with the same label
+    Replace { slot: usize, previous: Lens },
+    /// Took the lens out of the slot, moving all lenses behind it forward
+    Remove { slot: usize, lens: Lens },
+    /// There was no lens with the label to take out
+    Unchanged,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Step {
+    pub entry: Entry,
+    pub box_num: usize,
+    pub change: Change,
+}
+
+/// Boxes of lenses the initialization sequence arranges, along with a trace of the changes
+/// every entry made so that they can be undone
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LensLibrary {
+    boxes: Vec<Vec<Lens>>,
+    trace: Vec<Step>,
+}
+
+impl Default for LensLibrary {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Returns the box the lenses with the label go into
+pub fn box_num(label: &str) -> usize {
+    label.chars().fold(0u32, char_hash) as usize
+}
+
+impl LensLibrary {
+    pub fn new() -> Self {
+        Self {
+            boxes: vec![Vec::new(); BOXES],
+            trace: Vec::new(),
+        }
+    }
+
+    pub fn apply(&mut self, entry: &Entry) -> &Step {
+        let box_num = box_num(entry.character_sequence);
+        let current_box = &mut self.boxes[box_num];
+        let lens_index = current_box
+            .iter()
+            .position(|lens| lens.label == entry.character_sequence);
+        let change = match (entry.ending, lens_index) {
+            (EntryEnding::Minus, Some(slot)) => Change::Remove {
+                slot,
+                lens: current_box.remove(slot),
+            },
+            (EntryEnding::Minus, None) => Change::Unchanged,
+            (EntryEnding::Equal(focal_length), lens_index) => {
+                let lens = Lens {
+                    label: entry.character_sequence,
+                    focal_length,
+                };
+                match lens_index {
+                    Some(slot) => Change::Replace {
+                        slot,
+                        previous: std::mem::replace(&mut current_box[slot], lens),
+                    },
+                    None => {
+                        current_box.push(lens);
+                        Change::Insert(lens)
+                    }
+                }
+            }
+        };
+        self.trace.push(Step {
+            entry: entry.clone(),
+            box_num,
+            change,
+        });
+        &self.trace[self.trace.len() - 1]
+    }
+
+    /// Reverts the last step that has not been undone yet and returns it
+    pub fn undo(&mut self) -> Option<Step> {
+        let step = self.trace.pop()?;
+        let current_box = &mut self.boxes[step.box_num];
+        match step.change {
+            Change::Insert(_) => {
+                current_box.pop();
+            }
+            Change::Replace { slot, previous } => current_box[slot] = previous,
+            Change::Remove { slot, lens } => current_box.insert(slot, lens),
+            Change::Unchanged => {}
+        }
+        Some(step)
+    }
+
+    pub fn trace(&self) -> &[Step] {
+        &self.trace
+    }
+
+    pub fn lenses(&self, box_num: usize) -> &[Lens] {
+        &self.boxes[box_num]
+    }
+
+    /// Iterates over the boxes that contain lenses along with their numbers
+    pub fn boxes(&self) -> impl Iterator<Item = (usize, &[Lens])> {
+        self.boxes
+            .iter()
+            .enumerate()
+            .filter(|(_, lenses)| !lenses.is_empty())
+            .map(|(box_num, lenses)| (box_num, lenses.as_slice()))
+    }
+
+    /// Lists the lenses in every box that contains any the way the puzzle does, e.g.
+    /// `Box 0: [rn 1] [cm 2]`
+    pub fn snapshot(&self) -> String {
+        self.boxes()
+            .map(|(box_num, lenses)| {
+                let lenses: Vec<_> = lenses.iter().map(Lens::to_string).collect();
+                format!("Box {}: {}\n", box_num, lenses.join(" "))
+            })
+            .collect()
+    }
+
+    pub fn focusing_power(&self) -> u32 {
+        self.boxes()
+            .map(|(box_num, lenses)| -> u32 {
+                (box_num + 1) as u32
+                    * lenses
+                        .iter()
+                        .enumerate()
+                        .map(|(slot_num, lens)| (slot_num + 1) as u32 * lens.focal_length as u32)
+                        .sum::<u32>()
+            })
+            .sum()
+    }
+}
+
+/// Applies the entries one after another and lists the boxes after each of them like the
+/// puzzle's walkthrough
+pub fn walkthrough(entries: &[Entry]) -> String {
+    let mut library = LensLibrary::new();
+    entries
+        .iter()
+        .map(|entry| {
+            library.apply(entry);
+            format!("After \"{}\":\n{}\n", entry, library.snapshot())
+        })
+        .collect()
+}
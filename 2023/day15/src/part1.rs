@@ -1,3 +1,5 @@
+use std::fmt;
+
 use aoclib::AocError;
 use nom::{
     branch::alt,
@@ -8,18 +10,29 @@ use nom::{
     IResult,
 };
 
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub enum EntryEnding {
     Minus,
     Equal(u8),
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Entry {
     pub character_sequence: &'static str,
     pub ending: EntryEnding,
 }
 
+impl fmt::Display for Entry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.ending {
+            EntryEnding::Minus => write!(f, "{}-", self.character_sequence),
+            EntryEnding::Equal(focal_length) => {
+                write!(f, "{}={}", self.character_sequence, focal_length)
+            }
+        }
+    }
+}
+
 fn parse_minus(input: &str) -> IResult<&str, EntryEnding> {
     value(EntryEnding::Minus, char_parser('-'))(input)
 }
@@ -28,7 +41,7 @@ fn parse_equal(input: &str) -> IResult<&str, EntryEnding> {
     let (input, number) = preceded(char_parser('='), u8_parser)(input)?;
     Ok((input, EntryEnding::Equal(number)))
 }
-fn parse_entry(input: &'static str) -> IResult<&str, Entry> {
+fn parse_entry(input: &'static str) -> IResult<&'static str, Entry> {
     let (input, character_sequence) = alpha1(input)?;
     let (input, ending) = alt((parse_minus, parse_equal))(input)?;
     let entry = Entry {
@@ -0,0 +1,43 @@
+use aoclib::generator::{InputGenerator, Rng};
+
+const OPTICS: [char; 4] = ['/', '\\', '-', '|'];
+
+/// Generates square contraptions of empty space, mirrors and splitters
+#[derive(Debug, Clone)]
+pub struct Generator {
+    pub side_length: usize,
+    pub optics_density: f64,
+}
+
+impl Default for Generator {
+    fn default() -> Self {
+        Self {
+            side_length: 110,
+            optics_density: 0.1,
+        }
+    }
+}
+
+impl InputGenerator for Generator {
+    fn set_size(&mut self, size: usize) {
+        self.side_length = size;
+    }
+
+    fn generate(&self, rng: &mut Rng) -> String {
+        let side_length = self.side_length.max(1);
+        (0..side_length)
+            .map(|_| {
+                (0..side_length)
+                    .map(|_| {
+                        if rng.chance(self.optics_density) {
+                            *rng.choose(&OPTICS)
+                        } else {
+                            '.'
+                        }
+                    })
+                    .collect::<String>()
+                    + "\n"
+            })
+            .collect()
+    }
+}
@@ -1,5 +1,3 @@
-use std::collections::VecDeque;
-
 use aoclib::AocError;
 
 use crate::part1::{amount_of_energized_tiles, parse_grid, Direction, Position};
@@ -0,0 +1,35 @@
+use aoclib::generator::{InputGenerator, Rng};
+
+/// Generates maps of the heat loss of each city block as digits from 1 to 9
+#[derive(Debug, Clone)]
+pub struct Generator {
+    pub rows: usize,
+    pub cols: usize,
+}
+
+impl Default for Generator {
+    fn default() -> Self {
+        Self {
+            rows: 141,
+            cols: 141,
+        }
+    }
+}
+
+impl InputGenerator for Generator {
+    fn set_size(&mut self, size: usize) {
+        self.rows = size;
+        self.cols = size;
+    }
+
+    fn generate(&self, rng: &mut Rng) -> String {
+        (0..self.rows.max(1))
+            .map(|_| {
+                (0..self.cols.max(1))
+                    .map(|_| char::from(b'1' + rng.below(9) as u8))
+                    .collect::<String>()
+                    + "\n"
+            })
+            .collect()
+    }
+}
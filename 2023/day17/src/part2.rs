@@ -18,7 +18,7 @@ pub fn process(input: &'static str) -> Result<u32, AocError> {
         },
         Status {
             pos: Position { row: 0, col: 0 },
-            direction: Direction::Down,
+            direction: Direction::Right,
             steps_in_direction_taken: 0,
         },
     ];
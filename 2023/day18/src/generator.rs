@@ -0,0 +1,74 @@
+use aoclib::generator::{InputGenerator, Rng};
+
+/// Generates dig plans whose trenches are the outline of a row of bars, which never crosses
+/// itself
+///
+/// Both the plan of part 1 and the one hidden in the colors for part 2 are such outlines with
+/// the same number of bars
+#[derive(Debug, Clone)]
+pub struct Generator {
+    pub bars: usize,
+}
+
+impl Default for Generator {
+    fn default() -> Self {
+        Self { bars: 150 }
+    }
+}
+
+/// Returns the outline as (direction, distance) where the direction is one of R, D, L, U
+fn bar_outline(rng: &mut Rng, bars: usize, max_distance: usize) -> Vec<(char, usize)> {
+    // the bottom of the outline spans all bars with a single instruction
+    let max_width = (max_distance / bars).max(1);
+    let widths: Vec<usize> = (0..bars).map(|_| rng.between(1, max_width)).collect();
+    let mut heights: Vec<usize> = Vec::new();
+    (0..bars).for_each(|_| {
+        let height = loop {
+            let height = rng.between(1, max_distance);
+            if heights.last() != Some(&height) {
+                break height;
+            }
+        };
+        heights.push(height);
+    });
+    let mut outline = vec![('R', widths.iter().sum()), ('U', heights[bars - 1])];
+    (1..bars).rev().for_each(|bar| {
+        outline.push(('L', widths[bar]));
+        if heights[bar - 1] > heights[bar] {
+            outline.push(('U', heights[bar - 1] - heights[bar]));
+        } else {
+            outline.push(('D', heights[bar] - heights[bar - 1]));
+        }
+    });
+    outline.push(('L', widths[0]));
+    outline.push(('D', heights[0]));
+    outline
+}
+
+impl InputGenerator for Generator {
+    fn set_size(&mut self, size: usize) {
+        self.bars = size;
+    }
+
+    fn generate(&self, rng: &mut Rng) -> String {
+        let bars = self.bars.clamp(1, 255);
+        let small_outline = bar_outline(rng, bars, 255);
+        let large_outline = bar_outline(rng, bars, 0xfffff);
+        small_outline
+            .into_iter()
+            .zip(large_outline)
+            .map(|((direction, distance), (hex_direction, hex_distance))| {
+                let hex_direction = match hex_direction {
+                    'R' => 0,
+                    'D' => 1,
+                    'L' => 2,
+                    _ => 3,
+                };
+                format!(
+                    "{} {} (#{:05x}{})\n",
+                    direction, distance, hex_distance, hex_direction
+                )
+            })
+            .collect()
+    }
+}
@@ -1,9 +1,19 @@
+use std::env;
+
 use aoclib::AocError;
 
 use day19::part1::process;
 
 fn main() -> Result<(), AocError> {
     let input = include_str!("../../input.txt");
+    if env::args().any(|arg| arg == "--dot") {
+        print!("{}", day19::dot::export(input)?);
+        return Ok(());
+    }
+    if env::args().any(|arg| arg == "--explain") {
+        print!("{}", day19::explain::explain_part1(input)?);
+        return Ok(());
+    }
     let output = process(input)?;
     println!("=== Part 1 Result ===");
     println!("{}", output);
@@ -1,8 +1,27 @@
+use std::fmt;
+
+use aoclib::graph::Graph;
+
+/// What a rule does with a part, the workflow to jump to is given by its index into the
+/// workflows once the names are resolved
 #[derive(Debug, Clone, PartialEq, Eq)]
-pub enum WorkflowAction {
+pub enum WorkflowAction<T = usize> {
     Accept,
     Reject,
-    JumpTo(String),
+    JumpTo(T),
+}
+
+impl<T> WorkflowAction<T> {
+    pub fn try_map_target<U, E>(
+        self,
+        f: impl FnOnce(T) -> Result<U, E>,
+    ) -> Result<WorkflowAction<U>, E> {
+        Ok(match self {
+            Self::Accept => WorkflowAction::Accept,
+            Self::Reject => WorkflowAction::Reject,
+            Self::JumpTo(target) => WorkflowAction::JumpTo(f(target)?),
+        })
+    }
 }
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
@@ -11,6 +30,15 @@ pub enum RuleComparator {
     Greater,
 }
 
+impl RuleComparator {
+    pub fn compare(self, first_val: u32, second_val: u32) -> bool {
+        match self {
+            Self::Less => first_val < second_val,
+            Self::Greater => first_val > second_val,
+        }
+    }
+}
+
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub enum PartCategory {
     XtremelyGoodLooking,
@@ -19,6 +47,21 @@ pub enum PartCategory {
     Shiny,
 }
 
+/// Writes the condition the way the input does, e.g. `s<1351`
+pub fn condition(category: PartCategory, comp: RuleComparator, val: u32) -> String {
+    let category = match category {
+        PartCategory::XtremelyGoodLooking => 'x',
+        PartCategory::Musical => 'm',
+        PartCategory::Aerodynamic => 'a',
+        PartCategory::Shiny => 's',
+    };
+    let comp = match comp {
+        RuleComparator::Less => '<',
+        RuleComparator::Greater => '>',
+    };
+    format!("{}{}{}", category, comp, val)
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Part {
     pub x: u32,
@@ -27,19 +70,79 @@ pub struct Part {
     pub s: u32,
 }
 
+impl Part {
+    pub fn rating(&self, category: PartCategory) -> u32 {
+        match category {
+            PartCategory::XtremelyGoodLooking => self.x,
+            PartCategory::Musical => self.m,
+            PartCategory::Aerodynamic => self.a,
+            PartCategory::Shiny => self.s,
+        }
+    }
+
+    pub fn sum_ratings(&self) -> u32 {
+        self.x + self.m + self.a + self.s
+    }
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{{x={},m={},a={},s={}}}", self.x, self.m, self.a, self.s)
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
-pub enum WorkflowRule {
+pub enum WorkflowRule<T = usize> {
     Conditional {
         category: PartCategory,
         comp: RuleComparator,
         val: u32,
-        action: WorkflowAction,
+        action: WorkflowAction<T>,
     },
-    Otherwise(WorkflowAction),
+    Otherwise(WorkflowAction<T>),
+}
+
+impl<T> WorkflowRule<T> {
+    pub fn action(&self) -> &WorkflowAction<T> {
+        match self {
+            Self::Conditional { action, .. } => action,
+            Self::Otherwise(action) => action,
+        }
+    }
+
+    pub fn try_map_target<U, E>(
+        self,
+        f: impl FnOnce(T) -> Result<U, E>,
+    ) -> Result<WorkflowRule<U>, E> {
+        Ok(match self {
+            Self::Conditional {
+                category,
+                comp,
+                val,
+                action,
+            } => WorkflowRule::Conditional {
+                category,
+                comp,
+                val,
+                action: action.try_map_target(f)?,
+            },
+            Self::Otherwise(action) => WorkflowRule::Otherwise(action.try_map_target(f)?),
+        })
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
-pub struct Workflow {
+pub struct Workflow<T = usize> {
     pub name: String,
-    pub rules: Vec<WorkflowRule>,
+    pub rules: Vec<WorkflowRule<T>>,
+}
+
+/// All workflows, with the jumps between them as edges of a graph whose node indices are the
+/// workflow indices
+#[derive(Debug, Clone)]
+pub struct Workflows {
+    pub workflows: Vec<Workflow>,
+    pub graph: Graph<String>,
+    /// Index of the `in` workflow every part starts at
+    pub start: usize,
 }
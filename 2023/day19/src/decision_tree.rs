@@ -0,0 +1,285 @@
+use aoclib::AocError;
+
+use crate::datastructures::{
+    Part, PartCategory, RuleComparator, WorkflowAction, WorkflowRule, Workflows,
+};
+use crate::ranges::PartRange;
+
+/// Index of the node all accepted parts end at
+pub const ACCEPT: usize = 0;
+/// Index of the node all rejected parts end at
+pub const REJECT: usize = 1;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Node {
+    Accept,
+    Reject,
+    /// Continues at `pass` for parts fulfilling the condition and at `fail` for all others, the
+    /// condition is the one of the given rule of the given workflow
+    Test {
+        category: PartCategory,
+        comp: RuleComparator,
+        val: u32,
+        pass: usize,
+        fail: usize,
+        workflow: usize,
+        rule: usize,
+    },
+}
+
+/// Outcome of a rule's condition on the way through the tree
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Decision {
+    pub workflow: usize,
+    pub rule: usize,
+    pub fulfilled: bool,
+}
+
+/// Range of parts that get accepted along with the decisions that lead there
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AcceptedBox {
+    pub range: PartRange,
+    pub decisions: Vec<Decision>,
+}
+
+/// Workflows compiled into single conditions that continue at node indices
+///
+/// Workflows jumped to by only one rule are inlined, which lets the compiler drop their rules
+/// whose conditions are decided by the conditions on the way to them. Workflows jumped to by
+/// several rules are compiled once and shared.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DecisionTree {
+    nodes: Vec<Node>,
+    root: usize,
+}
+
+struct Compiler<'a> {
+    workflows: &'a Workflows,
+    /// Number of rules jumping to each workflow
+    uses: Vec<usize>,
+    /// Root nodes of the shared workflows compiled so far
+    shared: Vec<Option<usize>>,
+    /// Workflows being compiled, jumping to one of them again closes a cycle
+    path: Vec<usize>,
+    nodes: Vec<Node>,
+}
+
+impl<'a> Compiler<'a> {
+    fn new(workflows: &'a Workflows) -> Result<Self, AocError> {
+        let count = workflows.workflows.len();
+        if workflows.start >= count {
+            return Err(AocError::LogicError(format!(
+                "Start workflow {} is missing",
+                workflows.start
+            )));
+        }
+        let mut uses = vec![0; count];
+        workflows.workflows.iter().try_for_each(|workflow| {
+            workflow
+                .rules
+                .iter()
+                .try_for_each(|rule| match *rule.action() {
+                    WorkflowAction::JumpTo(target) if target >= count => {
+                        Err(AocError::LogicError(format!(
+                            "Workflow {} jumps to missing workflow {}",
+                            workflow.name, target
+                        )))
+                    }
+                    WorkflowAction::JumpTo(target) => {
+                        uses[target] += 1;
+                        Ok(())
+                    }
+                    _ => Ok(()),
+                })
+        })?;
+        Ok(Self {
+            workflows,
+            uses,
+            shared: vec![None; count],
+            path: Vec::new(),
+            nodes: vec![Node::Accept, Node::Reject],
+        })
+    }
+
+    /// Compiles the workflow for the parts in the range, which have to reach it
+    fn compile_workflow(&mut self, idx: usize, range: PartRange) -> Result<usize, AocError> {
+        if let Some(node) = self.shared[idx] {
+            return Ok(node);
+        }
+        if let Some(position) = self.path.iter().position(|&workflow| workflow == idx) {
+            let names: Vec<_> = self.path[position..]
+                .iter()
+                .chain([&idx])
+                .map(|&workflow| self.workflows.workflows[workflow].name.as_str())
+                .collect();
+            return Err(AocError::LogicError(format!(
+                "Workflows jump in a cycle: {}",
+                names.join(" -> ")
+            )));
+        }
+        self.path.push(idx);
+        let node = self.compile_rules(idx, 0, range)?;
+        self.path.pop();
+        Ok(node)
+    }
+
+    fn compile_rules(
+        &mut self,
+        idx: usize,
+        rule_idx: usize,
+        range: PartRange,
+    ) -> Result<usize, AocError> {
+        let workflows = self.workflows;
+        match workflows.workflows[idx].rules.get(rule_idx) {
+            // parts no rule applies to are rejected
+            None => Ok(REJECT),
+            Some(WorkflowRule::Otherwise(action)) => self.compile_action(action, range),
+            Some(&WorkflowRule::Conditional {
+                category,
+                comp,
+                val,
+                ref action,
+            }) => {
+                let (pass_range, fail_range) = range.split(category, comp, val);
+                // conditions decided by the earlier ones need no test
+                if pass_range.is_empty() {
+                    return self.compile_rules(idx, rule_idx + 1, fail_range);
+                }
+                if fail_range.is_empty() {
+                    return self.compile_action(action, pass_range);
+                }
+                let pass = self.compile_action(action, pass_range)?;
+                let fail = self.compile_rules(idx, rule_idx + 1, fail_range)?;
+                if pass == fail {
+                    return Ok(pass);
+                }
+                self.nodes.push(Node::Test {
+                    category,
+                    comp,
+                    val,
+                    pass,
+                    fail,
+                    workflow: idx,
+                    rule: rule_idx,
+                });
+                Ok(self.nodes.len() - 1)
+            }
+        }
+    }
+
+    fn compile_action(
+        &mut self,
+        action: &WorkflowAction,
+        range: PartRange,
+    ) -> Result<usize, AocError> {
+        match *action {
+            WorkflowAction::Accept => Ok(ACCEPT),
+            WorkflowAction::Reject => Ok(REJECT),
+            WorkflowAction::JumpTo(target) if self.uses[target] == 1 => {
+                self.compile_workflow(target, range)
+            }
+            WorkflowAction::JumpTo(target) => {
+                let node = self.compile_workflow(target, PartRange::default())?;
+                self.shared[target] = Some(node);
+                Ok(node)
+            }
+        }
+    }
+}
+
+impl DecisionTree {
+    /// Compiles the workflows reachable from the start workflow
+    ///
+    /// Returns a [`AocError::LogicError`] if parts could jump between workflows in a cycle or
+    /// the start workflow or a workflow jumped to is missing. Parsing already rejects unknown
+    /// names, this guards workflows changed or built by hand.
+    pub fn compile(workflows: &Workflows) -> Result<Self, AocError> {
+        let mut compiler = Compiler::new(workflows)?;
+        let root = compiler.compile_workflow(workflows.start, PartRange::default())?;
+        Ok(Self {
+            nodes: compiler.nodes,
+            root,
+        })
+    }
+
+    pub fn nodes(&self) -> &[Node] {
+        &self.nodes
+    }
+
+    pub fn root(&self) -> usize {
+        self.root
+    }
+
+    pub fn accepts(&self, part: &Part) -> bool {
+        let mut node = self.root;
+        loop {
+            match self.nodes[node] {
+                Node::Accept => break true,
+                Node::Reject => break false,
+                Node::Test {
+                    category,
+                    comp,
+                    val,
+                    pass,
+                    fail,
+                    ..
+                } => {
+                    node = if comp.compare(part.rating(category), val) {
+                        pass
+                    } else {
+                        fail
+                    }
+                }
+            }
+        }
+    }
+
+    /// Splits all parts into the disjoint ranges of parts that get accepted
+    pub fn accepted_boxes(&self) -> Vec<AcceptedBox> {
+        let mut accepted = Vec::new();
+        let mut boxes = vec![(
+            self.root,
+            AcceptedBox {
+                range: PartRange::default(),
+                decisions: Vec::new(),
+            },
+        )];
+        while let Some((node, accepted_box)) = boxes.pop() {
+            match self.nodes[node] {
+                Node::Accept => accepted.push(accepted_box),
+                Node::Reject => {}
+                Node::Test {
+                    category,
+                    comp,
+                    val,
+                    pass,
+                    fail,
+                    workflow,
+                    rule,
+                } => {
+                    let (pass_range, fail_range) = accepted_box.range.split(category, comp, val);
+                    [(pass, pass_range, true), (fail, fail_range, false)]
+                        .into_iter()
+                        .filter(|(_, range, _)| !range.is_empty())
+                        .for_each(|(next, range, fulfilled)| {
+                            let mut decisions = accepted_box.decisions.clone();
+                            decisions.push(Decision {
+                                workflow,
+                                rule,
+                                fulfilled,
+                            });
+                            boxes.push((next, AcceptedBox { range, decisions }));
+                        });
+                }
+            }
+        }
+        accepted
+    }
+
+    pub fn accepted_ranges(&self) -> Vec<PartRange> {
+        self.accepted_boxes()
+            .into_iter()
+            .map(|accepted_box| accepted_box.range)
+            .collect()
+    }
+}
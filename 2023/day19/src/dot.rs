@@ -0,0 +1,66 @@
+use aoclib::dot::Dot;
+use aoclib::AocError;
+
+use crate::datastructures::{condition, WorkflowAction, WorkflowRule, Workflows};
+use crate::parsing::parse_input;
+
+const ACCEPT: &str = "A";
+const REJECT: &str = "R";
+
+fn target<'a>(workflows: &'a Workflows, action: &WorkflowAction) -> &'a str {
+    match action {
+        WorkflowAction::Accept => ACCEPT,
+        WorkflowAction::Reject => REJECT,
+        WorkflowAction::JumpTo(target) => &workflows.workflows[*target].name,
+    }
+}
+
+/// Draws every workflow with an edge per rule, labelled with the rule's condition, and a
+/// dashed edge for the fallthrough
+pub fn workflows_to_dot(workflows: &Workflows) -> Dot {
+    let mut dot = Dot::digraph("workflows");
+    dot.node(ACCEPT, &[("shape", "doublecircle")])
+        .node(REJECT, &[("shape", "circle")]);
+    workflows
+        .workflows
+        .iter()
+        .enumerate()
+        .for_each(|(idx, workflow)| {
+            let shape = if idx == workflows.start {
+                "house"
+            } else {
+                "box"
+            };
+            dot.node(&workflow.name, &[("shape", shape)]);
+        });
+    workflows.workflows.iter().for_each(|workflow| {
+        workflow.rules.iter().enumerate().for_each(|(order, rule)| {
+            let (label, action, style) = match rule {
+                WorkflowRule::Conditional {
+                    category,
+                    comp,
+                    val,
+                    action,
+                } => (
+                    format!("{}: {}", order + 1, condition(*category, *comp, *val)),
+                    action,
+                    "solid",
+                ),
+                WorkflowRule::Otherwise(action) => {
+                    (format!("{}: otherwise", order + 1), action, "dashed")
+                }
+            };
+            dot.edge(
+                &workflow.name,
+                target(workflows, action),
+                &[("label", &label), ("style", style)],
+            );
+        })
+    });
+    dot
+}
+
+pub fn export(input: &str) -> Result<String, AocError> {
+    let (workflows, _) = parse_input(input)?;
+    Ok(workflows_to_dot(&workflows).to_string())
+}
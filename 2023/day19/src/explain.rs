@@ -0,0 +1,154 @@
+use aoclib::AocError;
+
+use crate::datastructures::{condition, Part, WorkflowAction, WorkflowRule, Workflows};
+use crate::decision_tree::{AcceptedBox, DecisionTree};
+use crate::parsing::parse_input;
+
+/// Workflow a part passes and the index of its rule that applies to the part, if any
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Step {
+    pub workflow: usize,
+    pub rule: Option<usize>,
+}
+
+/// Follows the part through the workflows and returns its steps and whether it gets accepted
+///
+/// Loops forever on cyclic workflows, compiling them into a [`DecisionTree`] rules these out.
+pub fn route(workflows: &Workflows, part: &Part) -> (Vec<Step>, bool) {
+    let mut steps = Vec::new();
+    let mut workflow = workflows.start;
+    loop {
+        let rules = &workflows.workflows[workflow].rules;
+        let rule = rules.iter().position(|rule| match *rule {
+            WorkflowRule::Conditional {
+                category,
+                comp,
+                val,
+                ..
+            } => comp.compare(part.rating(category), val),
+            WorkflowRule::Otherwise(_) => true,
+        });
+        steps.push(Step { workflow, rule });
+        match rule.map(|rule| rules[rule].action()) {
+            Some(WorkflowAction::Accept) => break (steps, true),
+            Some(WorkflowAction::Reject) | None => break (steps, false),
+            Some(&WorkflowAction::JumpTo(next)) => workflow = next,
+        }
+    }
+}
+
+/// Describes a rule the way the DOT export labels it, e.g. `px (2: m>2090)`
+fn describe_rule(workflows: &Workflows, workflow: usize, rule: usize, fulfilled: bool) -> String {
+    let workflow = &workflows.workflows[workflow];
+    let description = match workflow.rules[rule] {
+        WorkflowRule::Conditional {
+            category,
+            comp,
+            val,
+            ..
+        } => {
+            let negation = if fulfilled { "" } else { "not " };
+            format!("{}{}", negation, condition(category, comp, val))
+        }
+        WorkflowRule::Otherwise(_) => "otherwise".to_string(),
+    };
+    format!("{} ({}: {})", workflow.name, rule + 1, description)
+}
+
+fn verdict(accepted: bool) -> &'static str {
+    if accepted {
+        "A"
+    } else {
+        "R"
+    }
+}
+
+pub fn describe_route(workflows: &Workflows, part: &Part) -> String {
+    let (steps, accepted) = route(workflows, part);
+    let steps: Vec<_> = steps
+        .iter()
+        .map(|step| match step.rule {
+            Some(rule) => describe_rule(workflows, step.workflow, rule, true),
+            None => format!("{} (no rule)", workflows.workflows[step.workflow].name),
+        })
+        .chain([verdict(accepted).to_string()])
+        .collect();
+    format!("{}: {}", part, steps.join(" -> "))
+}
+
+pub fn describe_box(workflows: &Workflows, accepted_box: &AcceptedBox) -> String {
+    let decisions: Vec<_> = accepted_box
+        .decisions
+        .iter()
+        .map(|decision| {
+            describe_rule(
+                workflows,
+                decision.workflow,
+                decision.rule,
+                decision.fulfilled,
+            )
+        })
+        .chain([verdict(true).to_string()])
+        .collect();
+    format!(
+        "{} ({} combinations)\n    {}",
+        accepted_box.range,
+        accepted_box.range.count_combinations(),
+        decisions.join(" -> ")
+    )
+}
+
+/// Checks that every part the workflows accept lies in exactly one of the accepted boxes and
+/// every part they reject in none, following the workflows without the tree the boxes come from
+pub fn check_boxes(
+    workflows: &Workflows,
+    boxes: &[AcceptedBox],
+    parts: &[Part],
+) -> Result<(), AocError> {
+    parts.iter().try_for_each(|part| {
+        let (_, accepted) = route(workflows, part);
+        let containing = boxes
+            .iter()
+            .filter(|accepted_box| accepted_box.range.contains(part))
+            .count();
+        if containing == usize::from(accepted) {
+            Ok(())
+        } else {
+            Err(AocError::LogicError(format!(
+                "Part {} is {} but lies in {} accepted boxes",
+                part,
+                if accepted { "accepted" } else { "rejected" },
+                containing
+            )))
+        }
+    })
+}
+
+/// Lists the route every part takes through the workflows
+pub fn explain_part1(input: &str) -> Result<String, AocError> {
+    let (workflows, parts) = parse_input(input)?;
+    DecisionTree::compile(&workflows)?;
+    let lines: Vec<_> = parts
+        .iter()
+        .map(|part| describe_route(&workflows, part))
+        .collect();
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Lists every range of accepted parts with the decisions that lead to it
+pub fn explain_part2(input: &str) -> Result<String, AocError> {
+    let (workflows, parts) = parse_input(input)?;
+    let tree = DecisionTree::compile(&workflows)?;
+    let boxes = tree.accepted_boxes();
+    check_boxes(&workflows, &boxes, &parts)?;
+    let total: u64 = boxes
+        .iter()
+        .map(|accepted_box| accepted_box.range.count_combinations())
+        .sum();
+    let lines: Vec<_> = boxes
+        .iter()
+        .map(|accepted_box| describe_box(&workflows, accepted_box))
+        .chain([format!("total: {} combinations", total)])
+        .collect();
+    Ok(lines.join("\n") + "\n")
+}
@@ -0,0 +1,107 @@
+use aoclib::generator::{InputGenerator, Rng};
+
+const CATEGORIES: [char; 4] = ['x', 'm', 'a', 's'];
+
+/// Generates workflows without cycles and parts to sort with them
+///
+/// The workflows are generated in an order in which rules only jump to later workflows and every
+/// workflow but `in` is jumped to from at least one earlier workflow
+#[derive(Debug, Clone)]
+pub struct Generator {
+    pub workflows: usize,
+    pub parts: usize,
+    pub max_extra_rules: usize,
+}
+
+impl Default for Generator {
+    fn default() -> Self {
+        Self {
+            workflows: 500,
+            parts: 200,
+            max_extra_rules: 2,
+        }
+    }
+}
+
+fn workflow_names(count: usize) -> Vec<String> {
+    let mut names = vec!["in".to_string()];
+    let mut idx = 26;
+    while names.len() < count {
+        let mut name = String::new();
+        let mut rest = idx;
+        while rest > 0 {
+            name.insert(0, char::from(b'a' + (rest % 26) as u8));
+            rest /= 26;
+        }
+        if name != "in" {
+            names.push(name);
+        }
+        idx += 1;
+    }
+    names
+}
+
+fn condition(rng: &mut Rng) -> String {
+    let comp = if rng.chance(0.5) { '<' } else { '>' };
+    format!(
+        "{}{}{}",
+        rng.choose(&CATEGORIES),
+        comp,
+        rng.between(2, 3999)
+    )
+}
+
+impl InputGenerator for Generator {
+    fn set_size(&mut self, size: usize) {
+        self.workflows = size;
+        self.parts = size;
+    }
+
+    fn generate(&self, rng: &mut Rng) -> String {
+        let count = self.workflows.max(1);
+        let names = workflow_names(count);
+        let mut children: Vec<Vec<usize>> = vec![Vec::new(); count];
+        (1..count).for_each(|idx| children[rng.below(idx)].push(idx));
+
+        let workflows: String = (0..count)
+            .map(|idx| {
+                let mut actions: Vec<String> = children[idx]
+                    .iter()
+                    .map(|&child| names[child].clone())
+                    .collect();
+                actions.extend((0..rng.below(self.max_extra_rules + 1)).map(|_| {
+                    if idx + 1 < count && rng.chance(0.3) {
+                        names[rng.between(idx + 1, count - 1)].clone()
+                    } else if rng.chance(0.5) {
+                        "A".to_string()
+                    } else {
+                        "R".to_string()
+                    }
+                }));
+                if actions.is_empty() || rng.chance(0.5) {
+                    actions.push(if rng.chance(0.5) { "A" } else { "R" }.to_string());
+                }
+                rng.shuffle(&mut actions);
+                let fallback = actions.pop().unwrap();
+                let rules: Vec<String> = actions
+                    .into_iter()
+                    .map(|action| format!("{}:{}", condition(rng), action))
+                    .chain([fallback])
+                    .collect();
+                format!("{}{{{}}}\n", names[idx], rules.join(","))
+            })
+            .collect();
+        let parts: String = (0..self.parts.max(1))
+            .map(|_| {
+                format!(
+                    "{{x={},m={},a={},s={}}}\n",
+                    rng.between(1, 4000),
+                    rng.between(1, 4000),
+                    rng.between(1, 4000),
+                    rng.between(1, 4000)
+                )
+            })
+            .collect();
+        format!("{}\n{}", workflows, parts)
+    }
+}
@@ -1,14 +1,30 @@
 mod datastructures;
-mod parsing;
+pub mod decision_tree;
+pub mod dot;
+pub mod explain;
+pub mod generator;
+pub mod parsing;
 pub mod part1;
 pub mod part2;
+mod ranges;
+#[cfg(any(test, feature = "reference"))]
+pub mod reference;
 
 #[cfg(test)]
 mod tests {
+    use crate::datastructures::{PartCategory, RuleComparator, WorkflowAction, WorkflowRule};
+    use crate::decision_tree::{DecisionTree, Node, ACCEPT, REJECT};
+    use crate::dot;
+    use crate::explain;
+    use crate::generator::Generator;
     use crate::parsing;
     use crate::part1;
     use crate::part2;
+    use crate::reference;
+    use aoclib::generator::{mutate, InputGenerator, Rng};
+    use aoclib::test_generated_inputs;
     use aoclib::AocError;
+    use proptest::prelude::*;
 
     #[test]
     fn test_part1() -> Result<(), AocError> {
@@ -34,15 +50,13 @@ mod tests {
         }
         let lines_accepted = [true, false, true, false, true];
         let (workflows, parts) = parsing::parse_input(input)?;
+        let tree = DecisionTree::compile(&workflows)?;
         lines_accepted
             .into_iter()
             .zip(parts)
             .for_each(|(should_get_accepted, part)| {
                 println!("part: {:?}", part);
-                assert_eq!(
-                    should_get_accepted,
-                    part1::do_workflows_accept_part(&workflows, &part)
-                );
+                assert_eq!(should_get_accepted, tree.accepts(&part));
             });
         Ok(())
     }
@@ -60,4 +74,184 @@ mod tests {
         assert_eq!(expected_output, output);
         Ok(())
     }
+
+    #[test]
+    fn test_invalid_workflows() {
+        let cyclic = "in{x<10:ab,R}\nab{m>5:in,A}\n\n{x=1,m=2,a=3,s=4}\n";
+        assert!(matches!(
+            part2::process(cyclic),
+            Err(AocError::LogicError(_))
+        ));
+        let unknown_target = "in{x<10:ab,R}\n\n{x=1,m=2,a=3,s=4}\n";
+        assert!(matches!(
+            parsing::parse_input(unknown_target),
+            Err(AocError::ParseError(_))
+        ));
+        let without_start = "ab{x<10:A,R}\n\n{x=1,m=2,a=3,s=4}\n";
+        assert!(matches!(
+            part1::process(without_start),
+            Err(AocError::ParseError(_))
+        ));
+    }
+
+    #[test]
+    fn test_compile_decision_tree() -> Result<(), AocError> {
+        let x_below_10 = Node::Test {
+            category: PartCategory::XtremelyGoodLooking,
+            comp: RuleComparator::Less,
+            val: 10,
+            pass: ACCEPT,
+            fail: REJECT,
+            workflow: 0,
+            rule: 0,
+        };
+        // ab is inlined, where its first condition always holds
+        let implied = "in{x<10:ab,R}\nab{x<20:A,m>5:R,A}\nunused{a<5:A,R}\n\n{x=1,m=2,a=3,s=4}\n";
+        let tree = DecisionTree::compile(&parsing::parse_input(implied)?.0)?;
+        assert_eq!(&[Node::Accept, Node::Reject, x_below_10], tree.nodes());
+        assert_eq!(2, tree.root());
+        // ab is inlined, where its first condition never holds
+        let contradicted = "in{x<10:ab,R}\nab{x>20:in,A}\n\n{x=1,m=2,a=3,s=4}\n";
+        let tree = DecisionTree::compile(&parsing::parse_input(contradicted)?.0)?;
+        assert_eq!(&[Node::Accept, Node::Reject, x_below_10], tree.nodes());
+        // rules with the same outcome either way are dropped
+        let same_outcome = "in{x<10:ab,a>5:R,A}\nab{m<5:R,R}\n\n{x=1,m=2,a=3,s=4}\n";
+        let tree = DecisionTree::compile(&parsing::parse_input(same_outcome)?.0)?;
+        assert_eq!(4, tree.nodes().len());
+        // ab is shared and compiled once for any part
+        let shared = "in{x<10:ab,m<5:ab,R}\nab{x<20:A,R}\n\n{x=1,m=2,a=3,s=4}\n";
+        let tree = DecisionTree::compile(&parsing::parse_input(shared)?.0)?;
+        assert_eq!(5, tree.nodes().len());
+        assert_eq!(1 + 2 + 3 + 4, part1::process(shared)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_compile_invalid_workflows() -> Result<(), AocError> {
+        let cyclic = "in{x<10:ab,R}\nab{m>5:cd,A}\ncd{a>5:ab,A}\n\n{x=1,m=2,a=3,s=4}\n";
+        let result = parsing::parse_input(cyclic).and_then(|(w, _)| DecisionTree::compile(&w));
+        assert!(
+            matches!(&result, Err(AocError::LogicError(message)) if message.ends_with("ab -> cd -> ab")),
+            "{:?}",
+            result
+        );
+        // unknown names never make it to the compiler
+        let missing_target = "in{x<10:ab,R}\nab{m>5:cd,A}\n\n{x=1,m=2,a=3,s=4}\n";
+        let result =
+            parsing::parse_input(missing_target).and_then(|(w, _)| DecisionTree::compile(&w));
+        assert!(
+            matches!(&result, Err(AocError::ParseError(message)) if message.ends_with("unknown workflow cd")),
+            "{:?}",
+            result
+        );
+        // but indices changed afterwards do
+        let (workflows, _) = parsing::parse_input("in{x<10:A,R}\n\n{x=1,m=2,a=3,s=4}\n")?;
+        let mut jump_to_missing = workflows.clone();
+        jump_to_missing.workflows[0].rules[1] = WorkflowRule::Otherwise(WorkflowAction::JumpTo(1));
+        let mut missing_start = workflows;
+        missing_start.start = 1;
+        [jump_to_missing, missing_start]
+            .iter()
+            .for_each(|workflows| {
+                assert!(matches!(
+                    DecisionTree::compile(workflows),
+                    Err(AocError::LogicError(_))
+                ))
+            });
+        Ok(())
+    }
+
+    #[test]
+    fn test_explain() -> Result<(), AocError> {
+        let input = include_str!("../test_input1.txt");
+        let (workflows, parts) = parsing::parse_input(input)?;
+        assert_eq!(
+            "{x=787,m=2655,a=1222,s=2876}: in (2: otherwise) -> qqz (1: s>2770) -> qs (2: otherwise) -> lnx (1: m>1548) -> A",
+            explain::describe_route(&workflows, &parts[0])
+        );
+        assert!(explain::explain_part2(input)?.ends_with("total: 167409079868000 combinations\n"));
+
+        let tree = DecisionTree::compile(&workflows)?;
+        let mut boxes = tree.accepted_boxes();
+        explain::check_boxes(&workflows, &boxes, &parts)?;
+        let accepted = boxes
+            .iter()
+            .find(|accepted_box| accepted_box.range.contains(&parts[0]))
+            .cloned()
+            .unwrap();
+        assert_eq!(
+            "x=1..=4000, m=1..=4000, a=1..=4000, s=2771..=4000 (78720000000000 combinations)\n    in (1: not s<1351) -> qqz (1: s>2770) -> A",
+            explain::describe_box(&workflows, &accepted)
+        );
+        boxes.push(accepted);
+        assert!(matches!(
+            explain::check_boxes(&workflows, &boxes, &parts),
+            Err(AocError::LogicError(_))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_workflows_to_dot() -> Result<(), AocError> {
+        let dot = dot::export("in{s<1351:px,R}\npx{a<2006:A,m>2090:in,R}\n\n{x=1,m=2,a=3,s=4}\n")?;
+        let expected = r#"digraph "workflows" {
+    "A" [shape="doublecircle"];
+    "R" [shape="circle"];
+    "in" [shape="house"];
+    "px" [shape="box"];
+    "in" -> "px" [label="1: s<1351", style="solid"];
+    "in" -> "R" [label="2: otherwise", style="dashed"];
+    "px" -> "A" [label="1: a<2006", style="solid"];
+    "px" -> "in" [label="2: m>2090", style="solid"];
+    "px" -> "R" [label="3: otherwise", style="dashed"];
+}
+"#;
+        assert_eq!(expected, dot);
+        Ok(())
+    }
+
+    test_generated_inputs!(Generator::with_size(15), part1::process, part2::process);
+
+    proptest! {
+        #[test]
+        fn test_part2_matches_reference(seed in any::<u64>()) {
+            let generator = Generator {
+                workflows: 6,
+                parts: 1,
+                max_extra_rules: 2,
+            };
+            let input = generator.generate_static(seed);
+            prop_assert_eq!(reference::part2(input)?, part2::process(input)?);
+        }
+
+        #[test]
+        fn test_decision_tree_matches_workflows(seed in any::<u64>()) {
+            let input = Generator::with_size(30).generate_static(seed);
+            let (workflows, parts) = parsing::parse_input(input)?;
+            let tree = DecisionTree::compile(&workflows)?;
+            explain::check_boxes(&workflows, &tree.accepted_boxes(), &parts)?;
+            for part in parts {
+                prop_assert_eq!(
+                    reference::do_workflows_accept_part(&workflows, &part),
+                    tree.accepts(&part)
+                );
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn test_parse_input_never_panics(input in "[a-z{}<>:,=AR0-9\n]{0,60}") {
+            let result = parsing::parse_input(&input);
+            prop_assert!(matches!(result, Ok(_) | Err(AocError::ParseError(_))), "{:?}", result);
+        }
+
+        #[test]
+        fn test_parse_input_never_panics_on_mutated_input(seed in any::<u64>()) {
+            let mut rng = Rng::new(seed);
+            let input = mutate(&Generator::with_size(4).generate(&mut rng), &mut rng);
+            let result = parsing::parse_input(&input);
+            prop_assert!(matches!(result, Ok(_) | Err(AocError::ParseError(_))), "{:?}", result);
+        }
+    }
 }
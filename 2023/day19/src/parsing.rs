@@ -9,10 +9,14 @@ use nom::{
     IResult,
 };
 
+use aoclib::graph::Graph;
+
 use crate::datastructures::{
-    Part, PartCategory, RuleComparator, Workflow, WorkflowAction, WorkflowRule,
+    Part, PartCategory, RuleComparator, Workflow, WorkflowAction, WorkflowRule, Workflows,
 };
 
+const START_WORKFLOW: &str = "in";
+
 fn is_ascii_lowercase(c: char) -> bool {
     c.is_ascii_lowercase()
 }
@@ -25,16 +29,16 @@ fn parse_workflow_name(input: &str) -> IResult<&str, String> {
     map_res(take_while1(is_ascii_lowercase), to_string)(input)
 }
 
-fn parse_rule(input: &str) -> IResult<&str, WorkflowRule> {
+fn parse_rule(input: &str) -> IResult<&str, WorkflowRule<&str>> {
     alt((
         parse_conditional_rule,
         map_res(parse_action, |action| {
-            Ok::<WorkflowRule, AocError>(WorkflowRule::Otherwise(action))
+            Ok::<WorkflowRule<&str>, AocError>(WorkflowRule::Otherwise(action))
         }),
     ))(input)
 }
 
-fn parse_conditional_rule(input: &str) -> IResult<&str, WorkflowRule> {
+fn parse_conditional_rule(input: &str) -> IResult<&str, WorkflowRule<&str>> {
     let (input, category) = alt((
         value(PartCategory::XtremelyGoodLooking, char_parser('x')),
         value(PartCategory::Musical, char_parser('m')),
@@ -56,17 +60,17 @@ fn parse_conditional_rule(input: &str) -> IResult<&str, WorkflowRule> {
     Ok((input, rule))
 }
 
-fn parse_action(input: &str) -> IResult<&str, WorkflowAction> {
+fn parse_action(input: &str) -> IResult<&str, WorkflowAction<&str>> {
     alt((
-        map_res(parse_workflow_name, |name| {
-            Ok::<WorkflowAction, AocError>(WorkflowAction::JumpTo(name))
+        map_res(take_while1(is_ascii_lowercase), |name| {
+            Ok::<WorkflowAction<&str>, AocError>(WorkflowAction::JumpTo(name))
         }),
         value(WorkflowAction::Accept, char_parser('A')),
         value(WorkflowAction::Reject, char_parser('R')),
     ))(input)
 }
 
-fn parse_workflow_line(input: &str) -> IResult<&str, Workflow> {
+fn parse_workflow_line(input: &str) -> IResult<&str, Workflow<&str>> {
     let (input, name) = parse_workflow_name(input)?;
     let (input, rules) = delimited(
         char_parser('{'),
@@ -88,14 +92,63 @@ fn parse_part_line(input: &str) -> IResult<&str, Part> {
     Ok((input, part))
 }
 
-pub fn parse_input(input: &'static str) -> Result<(Vec<Workflow>, Vec<Part>), AocError> {
+/// Replaces the names of the workflows to jump to by their indices
+fn resolve_workflows(workflows: Vec<Workflow<&str>>) -> Result<Workflows, AocError> {
+    let mut graph = Graph::new_directed();
+    workflows.iter().try_for_each(|workflow| {
+        if graph.index_of(workflow.name.as_str()).is_some() {
+            return Err(AocError::ParseError(format!(
+                "Workflow {} is defined twice",
+                workflow.name
+            )));
+        }
+        graph.add_node(workflow.name.clone());
+        Ok(())
+    })?;
+    let workflows = workflows
+        .into_iter()
+        .enumerate()
+        .map(|(idx, workflow)| {
+            let mut resolve = |target: &str| {
+                let target_idx = graph.index_of(target).ok_or(AocError::ParseError(format!(
+                    "Workflow {} jumps to unknown workflow {}",
+                    workflow.name, target
+                )))?;
+                graph.add_edge_between(idx, target_idx);
+                Ok::<usize, AocError>(target_idx)
+            };
+            let rules = workflow
+                .rules
+                .into_iter()
+                .map(|rule| rule.try_map_target(&mut resolve))
+                .collect::<Result<_, _>>()?;
+            Ok(Workflow {
+                name: workflow.name,
+                rules,
+            })
+        })
+        .collect::<Result<_, AocError>>()?;
+    let start = graph
+        .index_of(START_WORKFLOW)
+        .ok_or(AocError::ParseError(format!(
+            "No '{}' workflow found",
+            START_WORKFLOW
+        )))?;
+    Ok(Workflows {
+        workflows,
+        graph,
+        start,
+    })
+}
+
+pub fn parse_input(input: &str) -> Result<(Workflows, Vec<Part>), AocError> {
     let (rest, (workflows, parts)) = separated_pair(
         many1(parse_workflow_line),
         line_ending,
         many1(parse_part_line),
     )(input)?;
     if rest.is_empty() {
-        Ok((workflows, parts))
+        Ok((resolve_workflows(workflows)?, parts))
     } else {
         Err(AocError::ParseError(format!(
             "Parsing workflows and parts left rest: {}",
@@ -0,0 +1,115 @@
+use std::fmt;
+
+use crate::datastructures::{Part, PartCategory, RuleComparator};
+
+const RANGE_MIN: u64 = 1;
+const RANGE_MAX: u64 = 4000;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Range {
+    min: u64,
+    max: u64,
+}
+
+impl Range {
+    pub fn new(min: u64, max: u64) -> Self {
+        Range {
+            min: min.max(RANGE_MIN),
+            max: max.min(RANGE_MAX),
+        }
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        Self {
+            min: self.min.max(other.min),
+            max: self.max.min(other.max),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.min > self.max
+    }
+
+    pub fn contains(&self, val: u32) -> bool {
+        self.min <= val as u64 && val as u64 <= self.max
+    }
+
+    pub fn width(&self) -> u64 {
+        if self.is_empty() {
+            0
+        } else {
+            self.max - self.min + 1
+        }
+    }
+}
+
+impl fmt::Display for Range {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}..={}", self.min, self.max)
+    }
+}
+
+impl Default for Range {
+    fn default() -> Self {
+        Self::new(RANGE_MIN, RANGE_MAX)
+    }
+}
+
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub struct PartRange {
+    x: Range,
+    m: Range,
+    a: Range,
+    s: Range,
+}
+
+impl PartRange {
+    fn restricted(&self, category: PartCategory, range: Range) -> Self {
+        let mut result = *self;
+        let restricted = match category {
+            PartCategory::XtremelyGoodLooking => &mut result.x,
+            PartCategory::Musical => &mut result.m,
+            PartCategory::Aerodynamic => &mut result.a,
+            PartCategory::Shiny => &mut result.s,
+        };
+        *restricted = restricted.intersection(&range);
+        result
+    }
+
+    /// Splits the range into the parts that fulfill the condition and the parts that do not
+    pub fn split(&self, category: PartCategory, comp: RuleComparator, val: u32) -> (Self, Self) {
+        let val = val as u64;
+        let (fulfilling, others) = match comp {
+            RuleComparator::Less => (
+                Range::new(RANGE_MIN, val.saturating_sub(1)),
+                Range::new(val, RANGE_MAX),
+            ),
+            RuleComparator::Greater => (Range::new(val + 1, RANGE_MAX), Range::new(RANGE_MIN, val)),
+        };
+        (
+            self.restricted(category, fulfilling),
+            self.restricted(category, others),
+        )
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.x.is_empty() || self.m.is_empty() || self.a.is_empty() || self.s.is_empty()
+    }
+
+    pub fn contains(&self, part: &Part) -> bool {
+        self.x.contains(part.x)
+            && self.m.contains(part.m)
+            && self.a.contains(part.a)
+            && self.s.contains(part.s)
+    }
+
+    pub fn count_combinations(&self) -> u64 {
+        self.x.width() * self.m.width() * self.a.width() * self.s.width()
+    }
+}
+
+impl fmt::Display for PartRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "x={}, m={}, a={}, s={}", self.x, self.m, self.a, self.s)
+    }
+}
@@ -0,0 +1,95 @@
+use aoclib::AocError;
+
+use crate::datastructures::{
+    Part, PartCategory, RuleComparator, Workflow, WorkflowAction, WorkflowRule, Workflows,
+};
+use crate::parsing::parse_input;
+
+const RANGE_MIN: u32 = 1;
+const RANGE_MAX: u32 = 4000;
+
+fn action(workflow: &Workflow, part: &Part) -> Option<WorkflowAction> {
+    workflow.rules.iter().find_map(|rule| match rule {
+        WorkflowRule::Conditional {
+            category,
+            comp,
+            val,
+            action,
+        } => comp
+            .compare(part.rating(*category), *val)
+            .then(|| action.clone()),
+        WorkflowRule::Otherwise(action) => Some(action.clone()),
+    })
+}
+
+/// Follows the rules of the workflows one by one instead of the compiled decision tree, loops
+/// forever on cyclic workflows
+pub fn do_workflows_accept_part(workflows: &Workflows, part: &Part) -> bool {
+    let mut workflow_idx = workflows.start;
+    loop {
+        match action(&workflows.workflows[workflow_idx], part) {
+            Some(WorkflowAction::Accept) => break true,
+            Some(WorkflowAction::Reject) | None => break false,
+            Some(WorkflowAction::JumpTo(next_workflow_idx)) => workflow_idx = next_workflow_idx,
+        }
+    }
+}
+
+/// Returns the sorted values at which a rule of the workflows can change its decision for the
+/// category, i.e. the starts of the intervals in which all values are treated the same
+fn interval_starts(workflows: &[Workflow], category: PartCategory) -> Vec<u32> {
+    let mut starts: Vec<u32> = workflows
+        .iter()
+        .flat_map(|workflow| &workflow.rules)
+        .filter_map(|rule| match *rule {
+            WorkflowRule::Conditional {
+                category: rule_category,
+                comp,
+                val,
+                ..
+            } if rule_category == category => match comp {
+                RuleComparator::Less => Some(val),
+                RuleComparator::Greater => Some(val + 1),
+            },
+            _ => None,
+        })
+        .filter(|&start| RANGE_MIN < start && start <= RANGE_MAX)
+        .chain([RANGE_MIN])
+        .collect();
+    starts.sort();
+    starts.dedup();
+    starts
+}
+
+/// Returns the start and the width of all intervals in which values are treated the same
+fn intervals(workflows: &[Workflow], category: PartCategory) -> Vec<(u32, u64)> {
+    let starts = interval_starts(workflows, category);
+    starts
+        .iter()
+        .zip(starts.iter().skip(1).chain([&(RANGE_MAX + 1)]))
+        .map(|(&start, &end)| (start, (end - start) as u64))
+        .collect()
+}
+
+/// Sends one representative part per combination of intervals through the workflows instead of
+/// splitting ranges, only feasible for few rules
+pub fn part2(input: &'static str) -> Result<u64, AocError> {
+    let (workflows, _) = parse_input(input)?;
+    let x_intervals = intervals(&workflows.workflows, PartCategory::XtremelyGoodLooking);
+    let m_intervals = intervals(&workflows.workflows, PartCategory::Musical);
+    let a_intervals = intervals(&workflows.workflows, PartCategory::Aerodynamic);
+    let s_intervals = intervals(&workflows.workflows, PartCategory::Shiny);
+    let mut combinations = 0;
+    for &(x, x_width) in &x_intervals {
+        for &(m, m_width) in &m_intervals {
+            for &(a, a_width) in &a_intervals {
+                for &(s, s_width) in &s_intervals {
+                    if do_workflows_accept_part(&workflows, &Part { x, m, a, s }) {
+                        combinations += x_width * m_width * a_width * s_width;
+                    }
+                }
+            }
+        }
+    }
+    Ok(combinations)
+}
@@ -1,9 +1,15 @@
+use std::env;
+
 use aoclib::AocError;
 
 use day20::part1::process;
 
 fn main() -> Result<(), AocError> {
     let input = include_str!("../../input.txt");
+    if env::args().any(|arg| arg == "--dot") {
+        print!("{}", day20::dot::export(input)?);
+        return Ok(());
+    }
     let output = process(input)?;
     println!("=== Part 1 Result ===");
     println!("{}", output);
@@ -0,0 +1,100 @@
+use std::collections::VecDeque;
+
+use crate::datastructures::{ModuleType, Modules, HIGH_PULSE, LOW_PULSE};
+
+/// Pulse on its way from one module to another
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Pulse {
+    pub from: usize,
+    pub to: usize,
+    pub pulse: bool,
+}
+
+/// Pulses sent during a single button press
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PressStatistics {
+    pub low_pulses: usize,
+    pub high_pulses: usize,
+}
+
+impl PressStatistics {
+    pub fn pulses(&self) -> usize {
+        self.low_pulses + self.high_pulses
+    }
+}
+
+/// Simulates the modules press by press, handing every pulse to an observer before it arrives
+#[derive(Debug, Clone)]
+pub struct Circuit {
+    modules: Modules,
+    presses: usize,
+}
+
+impl Circuit {
+    pub fn new(modules: Modules) -> Self {
+        Self {
+            modules,
+            presses: 0,
+        }
+    }
+
+    pub fn modules(&self) -> &Modules {
+        &self.modules
+    }
+
+    /// Number of times the button has been pressed
+    pub fn presses(&self) -> usize {
+        self.presses
+    }
+
+    /// Types and states of all modules, equal states behave equally on later presses
+    pub fn state(&self) -> &[Option<ModuleType>] {
+        &self.modules.module_types
+    }
+
+    pub fn press_button(&mut self) -> PressStatistics {
+        self.press_button_with(|_| {})
+    }
+
+    /// Presses the button and processes pulses in the order they were sent until none is left
+    pub fn press_button_with(&mut self, mut observe: impl FnMut(&Pulse)) -> PressStatistics {
+        self.presses += 1;
+        let mut statistics = PressStatistics::default();
+        let mut pulses = VecDeque::from([Pulse {
+            from: self.modules.button,
+            to: self.modules.broadcaster,
+            pulse: LOW_PULSE,
+        }]);
+        while let Some(pulse) = pulses.pop_front() {
+            observe(&pulse);
+            if pulse.pulse == HIGH_PULSE {
+                statistics.high_pulses += 1;
+            } else {
+                statistics.low_pulses += 1;
+            }
+            let Pulse { from, to, pulse } = pulse;
+            if let Some(sent) = self.modules.receive_and_propagate_pulse(to, pulse, from) {
+                pulses.extend(self.modules.targets(to).iter().map(|&target| Pulse {
+                    from: to,
+                    to: target,
+                    pulse: sent,
+                }));
+            }
+        }
+        statistics
+    }
+
+    /// Describes a pulse like the puzzle does, e.g. `broadcaster -low-> a`
+    pub fn describe(&self, pulse: &Pulse) -> String {
+        format!(
+            "{} -{}-> {}",
+            self.modules.name(pulse.from),
+            if pulse.pulse == HIGH_PULSE {
+                "high"
+            } else {
+                "low"
+            },
+            self.modules.name(pulse.to)
+        )
+    }
+}
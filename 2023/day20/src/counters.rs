@@ -0,0 +1,280 @@
+use crate::circuit::Circuit;
+use crate::datastructures::{ModuleType, Modules, LOW_PULSE};
+
+const MAX_BITS: usize = 63;
+const MAX_FIRINGS: usize = 1000;
+
+/// Chain of flip-flops that counts button presses in binary
+///
+/// The conjunction fed by some of the bits fires once the count has all of them set. Its low
+/// pulses then add the bits it sends them to onto the count, which usually resets it to zero.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BinaryCounter {
+    /// Flip-flops from the lowest to the highest bit
+    pub bits: Vec<usize>,
+    pub conjunction: usize,
+    /// Module outside the counter that the conjunction sends pulses to
+    pub output: usize,
+    /// Bits feeding the conjunction
+    pub target: u64,
+    /// Bits the conjunction sends pulses to
+    pub reset: u64,
+}
+
+/// Presses on which a counter fires, the first one and every `period` presses after it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Schedule {
+    pub first: u64,
+    pub period: u64,
+    /// Counts the counter fires at, after the last one they repeat from `repeat_from` on
+    pub fired_counts: Vec<u64>,
+    pub repeat_from: usize,
+}
+
+impl Schedule {
+    /// Count the counter fires at on its given firing, counting from zero
+    pub fn fired_count(&self, firing: u64) -> u64 {
+        let firing = match usize::try_from(firing) {
+            Ok(firing) if firing < self.fired_counts.len() => firing,
+            _ => {
+                let cycle = (self.fired_counts.len() - self.repeat_from) as u64;
+                self.repeat_from + ((firing - self.repeat_from as u64) % cycle) as usize
+            }
+        };
+        self.fired_counts[firing]
+    }
+}
+
+/// Smallest count from the given one on that has all the bits set
+fn next_count_with_bits(count: u64, bits: u64) -> u64 {
+    let missing = bits & !count;
+    if missing == 0 {
+        return count;
+    }
+    // keep the count above the highest missing bit, set it and only the required bits below
+    let highest = 63 - missing.leading_zeros();
+    let below = (1 << highest) - 1;
+    (count & !below & !(1 << highest)) | (1 << highest) | (bits & below)
+}
+
+impl BinaryCounter {
+    /// Follows the chain of flip-flops starting at the given module
+    pub fn find(modules: &Modules, start: usize) -> Result<Self, String> {
+        let is_flipflop =
+            |module: usize| matches!(modules.module_types[module], Some(ModuleType::FlipFlop(_)));
+        let is_conjunction = |module: usize| {
+            matches!(
+                modules.module_types[module],
+                Some(ModuleType::Conjunction(_))
+            )
+        };
+        if !is_flipflop(start) {
+            return Err(format!("{} is not a flip-flop", modules.name(start)));
+        }
+        let mut bits = Vec::new();
+        let mut conjunction = None;
+        let mut current = Some(start);
+        while let Some(bit) = current {
+            if bits.contains(&bit) || bits.len() == MAX_BITS {
+                return Err(format!(
+                    "the flip-flops after {} do not end within {} bits",
+                    modules.name(start),
+                    MAX_BITS
+                ));
+            }
+            bits.push(bit);
+            current = None;
+            for &target in modules.targets(bit) {
+                if is_flipflop(target) && current.is_none() {
+                    current = Some(target);
+                } else if is_conjunction(target) && conjunction.is_none_or(|c| c == target) {
+                    conjunction = Some(target);
+                } else {
+                    return Err(format!(
+                        "{} sends pulses to {} besides the next bit and the conjunction",
+                        modules.name(bit),
+                        modules.name(target)
+                    ));
+                }
+            }
+        }
+        let conjunction = conjunction.ok_or_else(|| {
+            format!(
+                "no flip-flop after {} feeds a conjunction",
+                modules.name(start)
+            )
+        })?;
+        let bit_of = |module: usize| bits.iter().position(|&bit| bit == module);
+
+        let target = bits
+            .iter()
+            .enumerate()
+            .filter(|(_, &bit)| modules.targets(bit).contains(&conjunction))
+            .fold(0, |target, (idx, _)| target | 1 << idx);
+        let mut reset = 0;
+        let mut output = None;
+        for &module in modules.targets(conjunction) {
+            match (bit_of(module), output) {
+                (Some(idx), _) => reset |= 1 << idx,
+                (None, None) => output = Some(module),
+                (None, Some(output)) => {
+                    return Err(format!(
+                        "{} sends pulses to both {} and {} outside its counter",
+                        modules.name(conjunction),
+                        modules.name(output),
+                        modules.name(module)
+                    ))
+                }
+            }
+        }
+        let output = output.ok_or_else(|| {
+            format!(
+                "{} sends no pulses out of its counter",
+                modules.name(conjunction)
+            )
+        })?;
+
+        if let Some(&input) = modules
+            .modules_targeting(conjunction)
+            .iter()
+            .find(|&&input| bit_of(input).is_none())
+        {
+            return Err(format!(
+                "{} is fed by {} from outside its counter",
+                modules.name(conjunction),
+                modules.name(input)
+            ));
+        }
+        for (idx, &bit) in bits.iter().enumerate() {
+            let previous = if idx == 0 {
+                modules.broadcaster
+            } else {
+                bits[idx - 1]
+            };
+            if let Some(&input) = modules
+                .modules_targeting(bit)
+                .iter()
+                .find(|&&input| input != previous && input != conjunction)
+            {
+                return Err(format!(
+                    "{} is fed by {} from outside its counter",
+                    modules.name(bit),
+                    modules.name(input)
+                ));
+            }
+        }
+        Ok(Self {
+            bits,
+            conjunction,
+            output,
+            target,
+            reset,
+        })
+    }
+
+    /// Works out the presses on which the conjunction fires from the wiring alone
+    ///
+    /// Every press adds one to the count and every firing adds the reset bits, both modulo the
+    /// size of the counter.
+    pub fn schedule(&self) -> Result<Schedule, String> {
+        let size = 1u64 << self.bits.len();
+        if self.target == 0 {
+            return Err("the conjunction is not fed by any bit".to_string());
+        }
+        // counts the presses after each firing start from, the firings repeat once one does
+        let mut starts = vec![0];
+        let mut fired_counts = Vec::new();
+        let mut firings: Vec<u64> = Vec::new();
+        let repeat_from = loop {
+            let count = starts[starts.len() - 1];
+            let fired_at = next_count_with_bits((count + 1) % size, self.target);
+            let presses = (fired_at + size - count) % size;
+            let press = firings
+                .last()
+                .map_or(Some(presses), |last| last.checked_add(presses))
+                .ok_or("the counter fires too late")?;
+            firings.push(press);
+            fired_counts.push(fired_at);
+            let start = (fired_at + self.reset) % size;
+            if start & self.target == self.target {
+                return Err(format!(
+                    "resetting after press {} keeps all bits feeding the conjunction set",
+                    press
+                ));
+            }
+            if let Some(repeat_from) = starts.iter().position(|&count| count == start) {
+                break repeat_from;
+            }
+            if firings.len() == MAX_FIRINGS {
+                return Err(format!(
+                    "the counter does not repeat within {} firings",
+                    MAX_FIRINGS
+                ));
+            }
+            starts.push(start);
+        };
+        let gaps: Vec<u64> = firings
+            .iter()
+            .scan(0, |previous, &press| {
+                let gap = press - *previous;
+                *previous = press;
+                Some(gap)
+            })
+            .collect();
+        let period = gaps.get(1).copied().unwrap_or(gaps[0]);
+        if gaps[1..].iter().any(|&gap| gap != period) || (repeat_from == 0 && gaps[0] != period) {
+            let presses: Vec<_> = firings.iter().take(5).map(u64::to_string).collect();
+            return Err(format!(
+                "the conjunction fires on presses {}, ...",
+                presses.join(", ")
+            ));
+        }
+        Ok(Schedule {
+            first: firings[0],
+            period,
+            fired_counts,
+            repeat_from,
+        })
+    }
+
+    /// Sets the flip-flops to the count, along with the conjunction's memory of them
+    pub fn set_count(&self, modules: &mut Modules, count: u64) {
+        let is_set = |idx: usize| count >> idx & 1 == 1;
+        self.bits.iter().enumerate().for_each(|(idx, &bit)| {
+            modules.module_types[bit] = Some(ModuleType::FlipFlop(is_set(idx)));
+        });
+        if let Some(ModuleType::Conjunction(memory)) = &mut modules.module_types[self.conjunction] {
+            memory.iter_mut().for_each(|(input, pulse)| {
+                if let Some(idx) = self.bits.iter().position(|bit| bit == input) {
+                    *pulse = is_set(idx);
+                }
+            });
+        }
+    }
+}
+
+/// Presses the button the given number of times and returns the presses on which each counter
+/// fired
+pub fn simulate_firings(
+    modules: &Modules,
+    counters: &[BinaryCounter],
+    presses: u64,
+) -> Vec<Vec<u64>> {
+    let mut circuit = Circuit::new(modules.clone());
+    let mut fired_on: Vec<Vec<u64>> = vec![Vec::new(); counters.len()];
+    for press in 1..=presses {
+        circuit.press_button_with(|pulse| {
+            counters
+                .iter()
+                .zip(fired_on.iter_mut())
+                .filter(|(counter, fired_on)| {
+                    pulse.from == counter.conjunction
+                        && pulse.to == counter.output
+                        && pulse.pulse == LOW_PULSE
+                        && fired_on.last() != Some(&press)
+                })
+                .for_each(|(_, fired_on)| fired_on.push(press));
+        });
+    }
+    fired_on
+}
@@ -1,13 +1,17 @@
-use std::collections::HashMap;
+use aoclib::graph::Graph;
 
 pub const LOW_PULSE: bool = false;
 pub const HIGH_PULSE: bool = true;
 
+pub const BUTTON: &str = "button";
+pub const BROADCASTER: &str = "broadcaster";
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ModuleType {
     Broadcast,
     FlipFlop(bool),
-    Conjunction(HashMap<String, bool>),
+    /// Last pulse remembered for each input module, sorted by the inputs' indices
+    Conjunction(Vec<(usize, bool)>),
 }
 
 impl ModuleType {
@@ -19,33 +23,16 @@ impl ModuleType {
         ModuleType::FlipFlop(false)
     }
 
-    pub fn new_conjunction(incoming: Vec<String>) -> Self {
+    pub fn new_conjunction(incoming: Vec<usize>) -> Self {
         let remember = incoming
             .into_iter()
-            .map(|s| (s, LOW_PULSE))
-            .collect::<HashMap<String, bool>>();
+            .map(|input| (input, LOW_PULSE))
+            .collect();
         ModuleType::Conjunction(remember)
     }
-}
-
-#[derive(Debug, Clone, PartialEq, Eq)]
-pub struct Module {
-    pub name: String,
-    pub module_type: ModuleType,
-    pub targets: Vec<String>,
-}
 
-impl Module {
-    pub fn new(name: String, module_type: ModuleType, targets: Vec<String>) -> Self {
-        Module {
-            name: name.to_string(),
-            module_type,
-            targets,
-        }
-    }
-
-    pub fn receive_and_propagate_pulse(&mut self, pulse: bool, from: &str) -> Option<bool> {
-        match &mut self.module_type {
+    pub fn receive_and_propagate_pulse(&mut self, pulse: bool, from: usize) -> Option<bool> {
+        match self {
             ModuleType::Broadcast => Some(pulse),
             ModuleType::FlipFlop(ref mut state) => {
                 if pulse == LOW_PULSE {
@@ -56,8 +43,11 @@ impl Module {
                 }
             }
             ModuleType::Conjunction(remember) => {
-                *remember.entry(from.to_string()).or_insert(LOW_PULSE) = pulse;
-                if remember.values().all(|&v| v == HIGH_PULSE) {
+                match remember.binary_search_by_key(&from, |&(input, _)| input) {
+                    Ok(idx) => remember[idx].1 = pulse,
+                    Err(idx) => remember.insert(idx, (from, pulse)),
+                }
+                if remember.iter().all(|&(_, v)| v == HIGH_PULSE) {
                     Some(LOW_PULSE)
                 } else {
                     Some(HIGH_PULSE)
@@ -66,3 +56,48 @@ impl Module {
         }
     }
 }
+
+/// Modules wired up as a graph with an edge from every module to each of its targets
+///
+/// The button and modules that are only sent pulses to, like `rx`, are nodes without a type
+#[derive(Debug, Clone)]
+pub struct Modules {
+    pub graph: Graph<String>,
+    /// Type and state of the module for each node of the graph
+    pub module_types: Vec<Option<ModuleType>>,
+    pub button: usize,
+    pub broadcaster: usize,
+}
+
+impl Modules {
+    pub fn name(&self, module: usize) -> &str {
+        self.graph.label(module)
+    }
+
+    pub fn index_of(&self, name: &str) -> Option<usize> {
+        self.graph.index_of(name)
+    }
+
+    pub fn targets(&self, module: usize) -> &[usize] {
+        self.graph.neighbours(module)
+    }
+
+    /// Modules that send pulses to the given one, sorted by name
+    pub fn modules_targeting(&self, module: usize) -> Vec<usize> {
+        let mut inputs = self.graph.predecessors(module);
+        inputs.sort_by_key(|&input| self.name(input));
+        inputs
+    }
+
+    /// Lets the module react to a pulse, returns the pulse it sends to all its targets
+    pub fn receive_and_propagate_pulse(
+        &mut self,
+        module: usize,
+        pulse: bool,
+        from: usize,
+    ) -> Option<bool> {
+        self.module_types[module]
+            .as_mut()
+            .and_then(|module_type| module_type.receive_and_propagate_pulse(pulse, from))
+    }
+}
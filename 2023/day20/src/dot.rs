@@ -0,0 +1,39 @@
+use aoclib::dot::Dot;
+use aoclib::AocError;
+
+use crate::datastructures::{ModuleType, Modules};
+use crate::parsing::parse_modules;
+
+fn shape(module_type: &Option<ModuleType>) -> (&'static str, &'static str) {
+    match module_type {
+        Some(ModuleType::Broadcast) => ("", "doubleoctagon"),
+        Some(ModuleType::FlipFlop(_)) => ("%", "box"),
+        Some(ModuleType::Conjunction(_)) => ("&", "invhouse"),
+        // the button and modules that only receive pulses
+        None => ("", "ellipse"),
+    }
+}
+
+/// Draws every module with a shape for its type and an edge to each of its targets
+pub fn modules_to_dot(modules: &Modules) -> Dot {
+    let mut dot = Dot::digraph("modules");
+    modules
+        .module_types
+        .iter()
+        .enumerate()
+        .for_each(|(module, module_type)| {
+            let (prefix, shape) = shape(module_type);
+            let label = format!("{}{}", prefix, modules.name(module));
+            dot.node(modules.name(module), &[("label", &label), ("shape", shape)]);
+        });
+    (0..modules.module_types.len()).for_each(|module| {
+        modules.targets(module).iter().for_each(|&target| {
+            dot.edge(modules.name(module), modules.name(target), &[]);
+        })
+    });
+    dot
+}
+
+pub fn export(input: &str) -> Result<String, AocError> {
+    Ok(modules_to_dot(&parse_modules(input)?).to_string())
+}
@@ -0,0 +1,91 @@
+use aoclib::generator::{InputGenerator, Rng};
+
+/// Generates module configurations without cycles
+///
+/// The modules are generated in an order in which they only send pulses to later modules and
+/// every module is the target of at least one earlier module. Modules without later targets
+/// send their pulses to `rx`.
+#[derive(Debug, Clone)]
+pub struct Generator {
+    pub modules: usize,
+    pub max_targets: usize,
+    pub conjunction_probability: f64,
+}
+
+impl Default for Generator {
+    fn default() -> Self {
+        Self {
+            modules: 58,
+            max_targets: 3,
+            conjunction_probability: 0.2,
+        }
+    }
+}
+
+fn module_names(count: usize) -> Vec<String> {
+    let mut names = Vec::new();
+    let mut idx = 26;
+    while names.len() < count {
+        let mut name = String::new();
+        let mut rest = idx;
+        while rest > 0 {
+            name.insert(0, char::from(b'a' + (rest % 26) as u8));
+            rest /= 26;
+        }
+        if name != "rx" {
+            names.push(name);
+        }
+        idx += 1;
+    }
+    names
+}
+
+impl InputGenerator for Generator {
+    fn set_size(&mut self, size: usize) {
+        self.modules = size;
+    }
+
+    fn generate(&self, rng: &mut Rng) -> String {
+        let count = self.modules.max(1);
+        // index 0 is the broadcaster
+        let names: Vec<String> = ["roadcaster".to_string()]
+            .into_iter()
+            .chain(module_names(count))
+            .collect();
+        let mut targets: Vec<Vec<usize>> = vec![Vec::new(); count + 1];
+        (1..=count).for_each(|idx| targets[rng.below(idx)].push(idx));
+        (1..count).for_each(|idx| {
+            (0..rng.below(self.max_targets.max(1))).for_each(|_| {
+                let target = rng.between(idx + 1, count);
+                if !targets[idx].contains(&target) {
+                    targets[idx].push(target);
+                }
+            })
+        });
+
+        let mut modules: Vec<String> = (0..=count)
+            .map(|idx| {
+                let symbol = if idx == 0 {
+                    'b'
+                } else if rng.chance(self.conjunction_probability) {
+                    '&'
+                } else {
+                    '%'
+                };
+                let module_targets: Vec<&str> = if targets[idx].is_empty() {
+                    vec!["rx"]
+                } else {
+                    targets[idx].iter().map(|&t| names[t].as_str()).collect()
+                };
+                format!(
+                    "{}{} -> {}\n",
+                    symbol,
+                    names[idx],
+                    module_targets.join(", ")
+                )
+            })
+            .collect();
+        rng.shuffle(&mut modules);
+        modules.concat()
+    }
+}
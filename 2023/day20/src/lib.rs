@@ -1,13 +1,24 @@
+pub mod circuit;
+pub mod counters;
 mod datastructures;
-mod parsing;
+pub mod dot;
+pub mod generator;
+pub mod parsing;
 pub mod part1;
 pub mod part2;
 
 #[cfg(test)]
 mod tests {
+    use crate::circuit::{Circuit, PressStatistics};
+    use crate::counters::{BinaryCounter, Schedule};
+    use crate::dot;
+    use crate::generator::Generator;
+    use crate::parsing::parse_modules;
     use crate::part1;
     use crate::part2;
+    use aoclib::generator::{check_generated_inputs, mutate, InputGenerator, Rng};
     use aoclib::AocError;
+    use proptest::prelude::*;
 
     #[test]
     fn test_part1a() -> Result<(), AocError> {
@@ -38,17 +49,238 @@ mod tests {
     }
 
     #[test]
-    fn test_part2() -> Result<(), AocError> {
-        let input = include_str!("../test_input2.txt");
+    fn test_trace_pulses() -> Result<(), AocError> {
+        let mut circuit = Circuit::new(parse_modules(include_str!("../test_input1a.txt"))?);
+        let mut pulses = Vec::new();
+        let statistics = circuit.press_button_with(|pulse| pulses.push(*pulse));
+        let trace: Vec<_> = pulses.iter().map(|pulse| circuit.describe(pulse)).collect();
+        assert_eq!(
+            vec![
+                "button -low-> broadcaster",
+                "broadcaster -low-> a",
+                "broadcaster -low-> b",
+                "broadcaster -low-> c",
+                "a -high-> b",
+                "b -high-> c",
+                "c -high-> inv",
+                "inv -low-> a",
+                "a -low-> b",
+                "b -low-> c",
+                "c -low-> inv",
+                "inv -high-> a",
+            ],
+            trace
+        );
+        assert_eq!(
+            PressStatistics {
+                low_pulses: 8,
+                high_pulses: 4
+            },
+            statistics
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_press_statistics() -> Result<(), AocError> {
+        let mut circuit = Circuit::new(parse_modules(include_str!("../test_input1b.txt"))?);
+        let statistics: Vec<_> = (0..4)
+            .map(|_| {
+                let statistics = circuit.press_button();
+                (statistics.low_pulses, statistics.high_pulses)
+            })
+            .collect();
+        assert_eq!(vec![(4, 4), (4, 2), (5, 3), (4, 2)], statistics);
+        assert_eq!(4, circuit.presses());
+        Ok(())
+    }
+
+    #[test]
+    fn test_modules_to_dot() -> Result<(), AocError> {
+        let expected = r#"digraph "modules" {
+    "button" [label="button", shape="ellipse"];
+    "broadcaster" [label="broadcaster", shape="doubleoctagon"];
+    "a" [label="%a", shape="box"];
+    "con" [label="&con", shape="invhouse"];
+    "inv" [label="&inv", shape="invhouse"];
+    "b" [label="%b", shape="box"];
+    "output" [label="output", shape="ellipse"];
+    "button" -> "broadcaster";
+    "broadcaster" -> "a";
+    "a" -> "con";
+    "a" -> "inv";
+    "con" -> "output";
+    "inv" -> "b";
+    "b" -> "con";
+}
+"#;
+        assert_eq!(expected, dot::export(include_str!("../test_input1b.txt"))?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_part2_unsupported() -> Result<(), AocError> {
+        let input = include_str!("../test_input1b.txt");
         if input.is_empty() {
             return Err(AocError::ParseError(
-                "Input file for test 2 is empty".to_string(),
+                "Input file for test 1 is empty".to_string(),
             ));
         }
-        let output = part2::process(input)?;
-        let expected_output = -1;
-        todo!("expected output part 2");
-        assert_eq!(expected_output, output);
+        let output = part2::process(input);
+        assert!(matches!(output, Err(AocError::UnsupportedInput(_))));
         Ok(())
     }
+
+    /// Builds counters of the given number of bits, bits feeding their conjunction and bits
+    /// reset by it, whose inverted outputs feed the conjunction in front of rx
+    fn counter_circuit(counters: &[(usize, u64, u64)]) -> String {
+        let letter = |idx: usize| char::from(b'a' + idx as u8);
+        let starts: Vec<_> = (0..counters.len())
+            .map(|counter| format!("f{}a", letter(counter)))
+            .collect();
+        let mut lines = vec![format!("broadcaster -> {}", starts.join(", "))];
+        counters
+            .iter()
+            .enumerate()
+            .for_each(|(counter, &(bits, target, reset))| {
+                let c = letter(counter);
+                (0..bits).for_each(|bit| {
+                    let mut targets = Vec::new();
+                    if bit + 1 < bits {
+                        targets.push(format!("f{}{}", c, letter(bit + 1)));
+                    }
+                    if target & 1 << bit != 0 {
+                        targets.push(format!("c{}", c));
+                    }
+                    lines.push(format!("%f{}{} -> {}", c, letter(bit), targets.join(", ")));
+                });
+                let mut targets: Vec<_> = (0..bits)
+                    .filter(|bit| reset & 1 << bit != 0)
+                    .map(|bit| format!("f{}{}", c, letter(bit)))
+                    .collect();
+                targets.push(format!("i{}", c));
+                lines.push(format!("&c{} -> {}", c, targets.join(", ")));
+                lines.push(format!("&i{} -> k", c));
+            });
+        lines.push("&k -> rx".to_string());
+        lines.iter().map(|line| format!("{}\n", line)).collect()
+    }
+
+    /// Presses the button until rx receives a low pulse
+    fn press_until_rx_low(input: &str, max_presses: usize) -> Result<Option<usize>, AocError> {
+        let modules = parse_modules(input)?;
+        let rx = modules.index_of("rx");
+        let mut circuit = Circuit::new(modules);
+        Ok((1..=max_presses).find(|_| {
+            let mut rx_low = false;
+            circuit.press_button_with(|pulse| {
+                rx_low |= !pulse.pulse && Some(pulse.to) == rx;
+            });
+            rx_low
+        }))
+    }
+
+    #[test]
+    fn test_counters_with_offset() -> Result<(), AocError> {
+        // the first counter resets to 1 and then fires every 9 presses starting at press 10
+        let input = counter_circuit(&[(4, 0b1010, 0b0111), (3, 0b111, 0b001)]);
+        let input: &'static str = input.leak();
+        let modules = parse_modules(input)?;
+        let schedules: Vec<_> = part2::find_counters(&modules)
+            .map_err(AocError::LogicError)?
+            .into_iter()
+            .map(|(_, schedule)| schedule)
+            .collect();
+        assert_eq!(
+            vec![
+                Schedule {
+                    first: 10,
+                    period: 9,
+                    fired_counts: vec![10, 10],
+                    repeat_from: 1,
+                },
+                Schedule {
+                    first: 7,
+                    period: 7,
+                    fired_counts: vec![7],
+                    repeat_from: 0,
+                }
+            ],
+            schedules
+        );
+        assert_eq!(28, part2::process(input)?);
+        assert_eq!(Some(28), press_until_rx_low(input, 100)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_unsupported_counters() -> Result<(), AocError> {
+        let counter = BinaryCounter {
+            bits: vec![0; 4],
+            conjunction: 0,
+            output: 0,
+            target: 0b1010,
+            reset: 0b0011,
+        };
+        // fires on presses 10, 11, 20, 21, ...
+        assert!(counter.schedule().is_err());
+
+        let input = counter_circuit(&[(4, 0b1111, 0b0001), (3, 0b111, 0b001)]);
+        let input: &'static str = input.replace("%fbc -> cb", "%fbc -> cb, fab").leak();
+        assert!(matches!(
+            part2::process(input),
+            Err(AocError::UnsupportedInput(_))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_generated_inputs() -> Result<(), AocError> {
+        check_generated_inputs(&Generator::with_size(12), |input| {
+            part1::process(input)?;
+            // generated circuits are not built like the binary counters part 2 relies on
+            match part2::process(input) {
+                Ok(_) | Err(AocError::UnsupportedInput(_)) => Ok(()),
+                Err(e) => Err(e),
+            }
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn test_counters_match_simulation(
+            counters in prop::collection::vec((2..6usize, any::<u64>(), any::<u64>()), 1..4)
+        ) {
+            let counters: Vec<_> = counters
+                .into_iter()
+                .map(|(bits, target, reset)| {
+                    let mask = (1 << bits) - 1;
+                    // the highest bit has to feed the conjunction to be part of the counter
+                    (bits, target & mask | 1 << (bits - 1), reset & mask)
+                })
+                .collect();
+            let input: &'static str = counter_circuit(&counters).leak();
+            match part2::process(input) {
+                Ok(press) if press <= 2000 => {
+                    prop_assert_eq!(Some(press as usize), press_until_rx_low(input, 2000)?);
+                }
+                Ok(_) | Err(AocError::UnsupportedInput(_)) | Err(AocError::LogicError(_)) => {}
+                Err(e) => prop_assert!(false, "{}", e),
+            }
+        }
+
+        #[test]
+        fn test_parse_modules_never_panics(input in "[a-c%&b\\-> ,\n]{0,40}") {
+            let result = parse_modules(&input);
+            prop_assert!(matches!(result, Ok(_) | Err(AocError::ParseError(_))), "{:?}", result);
+        }
+
+        #[test]
+        fn test_parse_modules_never_panics_on_mutated_input(seed in any::<u64>()) {
+            let mut rng = Rng::new(seed);
+            let input = mutate(&Generator::with_size(5).generate(&mut rng), &mut rng);
+            let result = parse_modules(&input);
+            prop_assert!(matches!(result, Ok(_) | Err(AocError::ParseError(_))), "{:?}", result);
+        }
+    }
 }
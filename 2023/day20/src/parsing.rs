@@ -1,5 +1,4 @@
-use std::collections::HashMap;
-
+use aoclib::graph::Graph;
 use aoclib::AocError;
 use nom::{
     bytes::complete::tag,
@@ -9,7 +8,7 @@ use nom::{
     IResult,
 };
 
-use crate::datastructures::{Module, ModuleType};
+use crate::datastructures::{ModuleType, Modules, BROADCASTER, BUTTON};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 struct ModuleData {
@@ -32,7 +31,7 @@ fn parse_module_data(input: &str) -> IResult<&str, ModuleData> {
     Ok((input, module_data))
 }
 
-pub fn parse_modules(input: &'static str) -> Result<Vec<Module>, AocError> {
+pub fn parse_modules(input: &str) -> Result<Modules, AocError> {
     let (rest, modules_data) = many1(parse_module_data)(input)?;
     if !rest.is_empty() {
         return Err(AocError::ParseError(format!(
@@ -40,30 +39,55 @@ pub fn parse_modules(input: &'static str) -> Result<Vec<Module>, AocError> {
             rest
         )));
     }
-    let mut incoming: HashMap<String, Vec<String>> = HashMap::new();
-    modules_data.iter().for_each(|data| {
-        data.targets.iter().for_each(|target| {
-            incoming
-                .entry(target.to_string())
-                .or_default()
-                .push(data.name.to_string());
-        })
-    });
+    let mut graph = Graph::new_directed();
+    let (button, broadcaster) = graph.add_edge(BUTTON.to_string(), BROADCASTER.to_string());
     let modules = modules_data
         .into_iter()
-        .map(|data| match data.module_type_symbol {
-            'b' => Module::new(
-                format!("b{}", data.name),
-                ModuleType::new_broadcast(),
-                data.targets,
-            ),
-            '%' => Module::new(data.name, ModuleType::new_flipflop(), data.targets),
-            '&' => {
-                let conj_type = ModuleType::new_conjunction(incoming[&data.name].clone());
-                Module::new(data.name, conj_type, data.targets)
+        .map(|data| {
+            let name = match data.module_type_symbol {
+                'b' => format!("b{}", data.name),
+                _ => data.name,
+            };
+            let module = graph.add_node(name);
+            if module == button {
+                return Err(AocError::ParseError(format!(
+                    "The {} is not a module",
+                    BUTTON
+                )));
             }
-            _ => unreachable!("Invalid module type symbol should have been caught by parser"),
+            if !graph.neighbours(module).is_empty() {
+                return Err(AocError::ParseError(format!(
+                    "Module {} is defined twice",
+                    graph.label(module)
+                )));
+            }
+            data.targets.into_iter().for_each(|target| {
+                let target = graph.add_node(target);
+                graph.add_edge_between(module, target);
+            });
+            Ok((module, data.module_type_symbol))
         })
-        .collect();
-    Ok(modules)
+        .collect::<Result<Vec<_>, _>>()?;
+    let mut module_types = vec![None; graph.len()];
+    modules.into_iter().try_for_each(|(module, symbol)| {
+        module_types[module] = Some(match symbol {
+            'b' => ModuleType::new_broadcast(),
+            '%' => ModuleType::new_flipflop(),
+            // a conjunction nobody sends pulses to is useless but still valid
+            '&' => ModuleType::new_conjunction(graph.predecessors(module)),
+            symbol => {
+                return Err(AocError::ParseError(format!(
+                    "Invalid module type symbol: {}",
+                    symbol
+                )))
+            }
+        });
+        Ok(())
+    })?;
+    Ok(Modules {
+        graph,
+        module_types,
+        button,
+        broadcaster,
+    })
 }
@@ -1,77 +1,21 @@
-use std::collections::{HashMap, VecDeque};
-
 use aoclib::AocError;
 
-use crate::datastructures::{Module, LOW_PULSE};
+use crate::circuit::Circuit;
 use crate::parsing::parse_modules;
 
-pub fn count_pulses(modules: &mut HashMap<String, Module>) -> (usize, usize) {
-    let mut pulse_chunks_to_process: VecDeque<Vec<_>> = VecDeque::new();
-    pulse_chunks_to_process.push_back(vec![(
-        "button".to_string(),
-        LOW_PULSE,
-        "broadcaster".to_string(),
-    )]);
-    let mut low_pulses = 0;
-    let mut high_pulses = 0;
-    loop {
-        if pulse_chunks_to_process.is_empty() {
-            break;
-        }
-        let chunk = pulse_chunks_to_process.pop_front().unwrap();
-
-        let new_chunks = chunk
-            .into_iter()
-            .filter_map(|(from, pulse, to)| {
-                if pulse == LOW_PULSE {
-                    low_pulses += 1;
-                } else {
-                    high_pulses += 1;
-                }
-                if let Some(current_module) = modules.get_mut(&to) {
-                    let pulse = current_module.receive_and_propagate_pulse(pulse, &from);
-                    pulse.map(|pulse| {
-                        current_module
-                            .targets
-                            .iter()
-                            .map(|target| (to.clone(), pulse, target.clone()))
-                            .collect()
-                    })
-                } else {
-                    None
-                }
-            })
-            .collect::<Vec<_>>();
-        pulse_chunks_to_process.extend(new_chunks);
-    }
-    (low_pulses, high_pulses)
-}
-
 pub fn process(input: &'static str) -> Result<usize, AocError> {
-    let modules = parse_modules(input)?;
-    let mut modules: HashMap<_, _> = modules
-        .into_iter()
-        .map(|module| (module.name.clone(), module))
-        .collect();
+    let mut circuit = Circuit::new(parse_modules(input)?);
     let mut low_pulses = 0;
     let mut high_pulses = 0;
     let mut seen_states = Vec::new();
-    seen_states.push(
-        modules
-            .iter()
-            .map(|(name, module)| (name.clone(), module.module_type.clone()))
-            .collect::<HashMap<_, _>>(),
-    );
+    seen_states.push(circuit.state().to_vec());
     let mut pulse_counts = Vec::new();
     pulse_counts.push((low_pulses, high_pulses));
     for iteration in 1..=1000 {
-        let (low, high) = count_pulses(&mut modules);
-        low_pulses += low;
-        high_pulses += high;
-        let state = modules
-            .iter()
-            .map(|(name, module)| (name.clone(), module.module_type.clone()))
-            .collect::<HashMap<_, _>>();
+        let statistics = circuit.press_button();
+        low_pulses += statistics.low_pulses;
+        high_pulses += statistics.high_pulses;
+        let state = circuit.state().to_vec();
         if let Some(previous_occurence_iteration) = seen_states
             .iter()
             .position(|prev_state| *prev_state == state)
@@ -1,112 +1,238 @@
-use std::collections::{HashMap, VecDeque};
-
+use aoclib::assumptions::Assumptions;
+use aoclib::modular::solve_congruences;
 use aoclib::AocError;
-use num::integer::gcd;
 
-use crate::datastructures::{Module, HIGH_PULSE, LOW_PULSE};
+use crate::circuit::Circuit;
+use crate::counters::{simulate_firings, BinaryCounter, Schedule};
+use crate::datastructures::{ModuleType, Modules, LOW_PULSE};
 use crate::parsing::parse_modules;
 
-pub fn press_button_and_abort_on_rx_low(
-    modules: &mut HashMap<String, Module>,
-    key_module_name: &str,
-    channeling_module_counts: &mut HashMap<String, usize>,
-    channeling_cycle_counts: &mut HashMap<String, usize>,
-    iteration: usize,
-) -> bool {
-    let mut pulse_chunks_to_process: VecDeque<Vec<_>> = VecDeque::new();
-    pulse_chunks_to_process.push_back(vec![(
-        "button".to_string(),
-        LOW_PULSE,
-        "broadcaster".to_string(),
-    )]);
-    loop {
-        if pulse_chunks_to_process.is_empty() {
-            break;
-        }
-        let chunk = pulse_chunks_to_process.pop_front().unwrap();
+const RX: &str = "rx";
+/// Presses on which all counters fire that are simulated before giving up on rx
+const MAX_CANDIDATES: usize = 1000;
+const MAX_SIMULATED_PRESSES: u64 = 100_000;
 
-        let mut new_chunks = Vec::new();
-        for (from, pulse, to) in chunk {
-            if pulse == HIGH_PULSE && to == key_module_name {
-                channeling_module_counts
-                    .entry(from.clone())
-                    .and_modify(|count| *count += 1)
-                    .or_insert(1);
-                channeling_cycle_counts
-                    .entry(from.clone())
-                    .or_insert(iteration);
+fn rx_feeders(modules: &Modules) -> Vec<usize> {
+    modules
+        .index_of(RX)
+        .map_or(Vec::new(), |rx| modules.modules_targeting(rx))
+}
 
-                if channeling_module_counts.values().all(|&count| count >= 1) {
-                    return true;
-                }
-            }
-            if let Some(current_module) = modules.get_mut(&to) {
-                let pulse = current_module.receive_and_propagate_pulse(pulse, &from);
-                if let Some(pulse) = pulse {
-                    new_chunks.push(
-                        current_module
-                            .targets
-                            .iter()
-                            .map(|target| (to.clone(), pulse, target.clone()))
-                            .collect(),
-                    );
-                }
-            }
-        }
-        pulse_chunks_to_process.extend(new_chunks);
+fn single_rx_feeder(modules: &Modules) -> Option<usize> {
+    match rx_feeders(modules)[..] {
+        [single] => Some(single),
+        _ => None,
     }
-    false
 }
 
-pub fn process(input: &'static str) -> Result<usize, AocError> {
-    let modules = parse_modules(input)?;
-    let mut modules: HashMap<_, _> = modules
-        .into_iter()
-        .map(|module| (module.name.clone(), module))
-        .collect();
-
-    let channel_into_rx = modules
-        .values()
-        .filter(|module| module.targets.contains(&"rx".to_string()))
-        .cloned()
-        .collect::<Vec<_>>();
-    let key_module = match &channel_into_rx[..] {
-        [single] => single,
-        _ => {
-            return Err(AocError::LogicError(
-                "Expected single module with rx as target".to_string(),
-            ))
-        }
-    };
+fn is_conjunction(modules: &Modules, module: usize) -> bool {
+    matches!(
+        modules.module_types[module],
+        Some(ModuleType::Conjunction(_))
+    )
+}
 
-    let mut channeling_module_counts: HashMap<String, usize> = modules
+/// Splits the circuit into the sub-circuits the broadcaster sends pulses to, each of which has
+/// to be a binary counter
+pub fn find_counters(modules: &Modules) -> Result<Vec<(BinaryCounter, Schedule)>, String> {
+    if modules.targets(modules.broadcaster).is_empty() {
+        return Err("the broadcaster sends no pulses".to_string());
+    }
+    modules
+        .targets(modules.broadcaster)
         .iter()
-        .filter_map(|(name, module)| {
-            if module.targets.contains(&key_module.name) {
-                Some((name.clone(), 0))
+        .map(|&start| {
+            let counter = BinaryCounter::find(modules, start)?;
+            let schedule = counter.schedule().map_err(|message| {
+                format!(
+                    "counter of {}: {}",
+                    modules.name(counter.conjunction),
+                    message
+                )
+            })?;
+            Ok((counter, schedule))
+        })
+        .collect()
+}
+
+pub fn assumptions() -> Assumptions<Modules> {
+    Assumptions::new()
+        .assume("exactly one module feeds rx", |modules: &Modules| {
+            let feeding_modules = rx_feeders(modules);
+            if feeding_modules.len() == 1 {
+                Ok(())
             } else {
-                None
+                let names: Vec<_> = feeding_modules
+                    .iter()
+                    .map(|&module| modules.name(module))
+                    .collect();
+                Err(format!(
+                    "{} modules feed rx: [{}]",
+                    feeding_modules.len(),
+                    names.join(", ")
+                ))
             }
         })
+        .assume(
+            "the module feeding rx is a conjunction",
+            |modules: &Modules| match single_rx_feeder(modules) {
+                Some(feeder) if !is_conjunction(modules, feeder) => Err(format!(
+                    "module {} is not a conjunction",
+                    modules.name(feeder)
+                )),
+                _ => Ok(()),
+            },
+        )
+        .assume(
+            "the broadcaster sends pulses to binary counters",
+            |modules: &Modules| find_counters(modules).map(|_| ()),
+        )
+        .assume(
+            "the rx feeder is fed by an inverter for each counter",
+            |modules: &Modules| {
+                let (Some(feeder), Ok(counters)) =
+                    (single_rx_feeder(modules), find_counters(modules))
+                else {
+                    return Ok(()); // reported by the other assumptions
+                };
+                let mut outputs: Vec<_> =
+                    counters.iter().map(|(counter, _)| counter.output).collect();
+                outputs.sort_by_key(|&output| modules.name(output));
+                if outputs != modules.modules_targeting(feeder) {
+                    return Err(format!(
+                        "{} is not fed by exactly the counters' outputs",
+                        modules.name(feeder)
+                    ));
+                }
+                counters.iter().try_for_each(|(counter, _)| {
+                    let output = counter.output;
+                    if is_conjunction(modules, output)
+                        && modules.modules_targeting(output) == [counter.conjunction]
+                        && modules.targets(output) == [feeder]
+                    {
+                        Ok(())
+                    } else {
+                        Err(format!(
+                            "{} does not only invert {}",
+                            modules.name(output),
+                            modules.name(counter.conjunction)
+                        ))
+                    }
+                })
+            },
+        )
+        .assume(
+            "simulated counters fire on the presses read from their wiring",
+            |modules: &Modules| {
+                let Ok(counters) = find_counters(modules) else {
+                    return Ok(()); // reported by the counters assumption
+                };
+                let (counters, schedules): (Vec<_>, Vec<_>) = counters.into_iter().unzip();
+                let max_presses = schedules
+                    .iter()
+                    .map(|schedule| schedule.first.saturating_add(schedule.period))
+                    .max()
+                    .unwrap_or(0)
+                    .min(MAX_SIMULATED_PRESSES);
+                let simulated = simulate_firings(modules, &counters, max_presses);
+                counters.iter().zip(schedules).zip(simulated).try_for_each(
+                    |((counter, schedule), presses)| {
+                        let expected: Vec<_> = (schedule.first..=max_presses)
+                            .step_by(schedule.period as usize)
+                            .collect();
+                        let mismatch = (0..presses.len().max(expected.len()))
+                            .find(|&idx| presses.get(idx) != expected.get(idx));
+                        match mismatch {
+                            None => Ok(()),
+                            Some(idx) => Err(format!(
+                                "{} fired for the {}. time on press {:?} instead of {:?}",
+                                modules.name(counter.conjunction),
+                                idx + 1,
+                                presses.get(idx),
+                                expected.get(idx)
+                            )),
+                        }
+                    },
+                )
+            },
+        )
+}
+
+/// Finds the first press on which every counter fires, they fire together again after
+/// multiples of the returned period
+pub fn first_common_press(schedules: &[Schedule]) -> Result<Option<(u64, u64)>, AocError> {
+    let congruences: Vec<_> = schedules
+        .iter()
+        .map(|schedule| (schedule.first % schedule.period, schedule.period))
         .collect();
-    let mut channeling_cycle_counts: HashMap<String, usize> = HashMap::new();
+    let Some((residue, modulus)) = solve_congruences(&congruences)? else {
+        return Ok(None);
+    };
+    // every counter only starts firing with its first press
+    let earliest = schedules
+        .iter()
+        .map(|schedule| schedule.first)
+        .max()
+        .unwrap_or(1);
+    let press = match earliest.checked_sub(residue) {
+        Some(missing) => missing
+            .div_ceil(modulus)
+            .checked_mul(modulus)
+            .and_then(|steps| steps.checked_add(residue)),
+        None => Some(residue),
+    };
+    press
+        .map(|press| Some((press, modulus)))
+        .ok_or_else(|| AocError::MathError("The common press is too large".to_string()))
+}
 
-    for iteration in 1.. {
-        if press_button_and_abort_on_rx_low(
-            &mut modules,
-            &key_module.name,
-            &mut channeling_module_counts,
-            &mut channeling_cycle_counts,
-            iteration,
-        ) {
-            let rx_iteration = channeling_cycle_counts
-                .values()
-                .fold(1usize, |acc, &cycle_count| {
-                    acc * cycle_count / gcd(acc, cycle_count)
-                });
-            return Ok(rx_iteration);
+/// Simulates the given press on which all counters fire from the counts they have before it
+///
+/// Whether rx receives a low pulse depends on the inverters feeding its conjunction sending
+/// their high pulses at overlapping times within the press.
+fn rx_receives_low_pulse(
+    modules: &Modules,
+    counters: &[(BinaryCounter, Schedule)],
+    press: u64,
+) -> bool {
+    let mut modules = modules.clone();
+    counters.iter().for_each(|(counter, schedule)| {
+        let size = 1 << counter.bits.len();
+        let fired_count = schedule.fired_count((press - schedule.first) / schedule.period);
+        counter.set_count(&mut modules, (fired_count + size - 1) % size);
+    });
+    let rx = modules.index_of(RX);
+    let mut circuit = Circuit::new(modules);
+    let mut rx_low = false;
+    circuit.press_button_with(|pulse| rx_low |= Some(pulse.to) == rx && pulse.pulse == LOW_PULSE);
+    rx_low
+}
+
+/// Finds the presses on which all counters fire from their schedules and simulates them until
+/// rx receives a low pulse
+pub fn process(input: &'static str) -> Result<u64, AocError> {
+    let modules = parse_modules(input)?;
+    assumptions().check(&modules)?;
+
+    let counters = find_counters(&modules).map_err(AocError::LogicError)?;
+    let schedules: Vec<_> = counters
+        .iter()
+        .map(|(_, schedule)| schedule.clone())
+        .collect();
+    let (first, period) = first_common_press(&schedules)?.ok_or(AocError::LogicError(
+        "The counters never fire on the same press".to_string(),
+    ))?;
+    let mut press = first;
+    for _ in 0..MAX_CANDIDATES {
+        if rx_receives_low_pulse(&modules, &counters, press) {
+            return Ok(press);
         }
+        press = press
+            .checked_add(period)
+            .ok_or_else(|| AocError::MathError("The common press is too large".to_string()))?;
     }
-    unreachable!()
+    Err(AocError::LogicError(format!(
+        "rx receives no low pulse on the first {} presses all counters fire on",
+        MAX_CANDIDATES
+    )))
 }
-
@@ -0,0 +1,52 @@
+use aoclib::generator::{InputGenerator, Rng};
+
+/// Generates square gardens that satisfy the assumptions of part 2: the start is in the centre
+/// and its row, its column and the edges are free of rocks
+#[derive(Debug, Clone)]
+pub struct Generator {
+    /// Side length of the garden, rounded up to the next odd number
+    pub side_length: usize,
+    pub rock_density: f64,
+}
+
+impl Default for Generator {
+    fn default() -> Self {
+        Self {
+            side_length: 131,
+            rock_density: 0.1,
+        }
+    }
+}
+
+impl InputGenerator for Generator {
+    fn set_size(&mut self, size: usize) {
+        self.side_length = size;
+    }
+
+    fn generate(&self, rng: &mut Rng) -> String {
+        let side_length = self.side_length.max(3) | 1;
+        let centre = side_length / 2;
+        (0..side_length)
+            .map(|row| {
+                (0..side_length)
+                    .map(|col| {
+                        let is_clear = row == centre
+                            || col == centre
+                            || row == 0
+                            || col == 0
+                            || row == side_length - 1
+                            || col == side_length - 1;
+                        if row == centre && col == centre {
+                            'S'
+                        } else if !is_clear && rng.chance(self.rock_density) {
+                            '#'
+                        } else {
+                            '.'
+                        }
+                    })
+                    .collect::<String>()
+                    + "\n"
+            })
+            .collect()
+    }
+}
@@ -1,10 +1,13 @@
+pub mod generator;
 pub mod part1;
 pub mod part2;
 
 #[cfg(test)]
 mod tests {
+    use crate::generator::Generator;
     use crate::part1;
     use crate::part2;
+    use aoclib::generator::{check_generated_inputs, InputGenerator};
     use aoclib::grid::Grid;
     use aoclib::AocError;
 
@@ -24,17 +27,84 @@ mod tests {
     }
 
     #[test]
-    fn test_part2() -> Result<(), AocError> {
-        let input = include_str!("../test_input2.txt");
+    fn test_part2_assumptions() -> Result<(), AocError> {
+        let input = include_str!("../test_input1.txt");
         if input.is_empty() {
             return Err(AocError::ParseError(
-                "Input file for test 2 is empty".to_string(),
+                "Input file for test 1 is empty".to_string(),
             ));
         }
-        let output = part2::process(input)?;
-        let expected_output = -1;
-        todo!("expected output part 2");
+        let grid = Grid::parse_from(input, part1::parse_tile)?;
+        let violated: Vec<_> = part2::assumptions()
+            .violations(&grid)
+            .into_iter()
+            .map(|violation| violation.assumption)
+            .collect();
+        assert_eq!(
+            vec!["the row and column of the start are free of rocks"],
+            violated
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_generated_inputs() -> Result<(), AocError> {
+        check_generated_inputs(&Generator::with_size(11), |input| {
+            part1::process(input)?;
+            let grid = Grid::parse_from(input, part1::parse_tile)?;
+            part2::assumptions().check(&grid)
+        })
+    }
+
+    #[test]
+    fn test_part2_exact() -> Result<(), AocError> {
+        let input = include_str!("../test_input1.txt");
+        let grid = Grid::parse_from(input, part1::parse_tile)?;
+        let expected_outputs = [
+            (6, 16),
+            (10, 50),
+            (50, 1594),
+            (100, 6536),
+            (500, 167004),
+            (1000, 668697),
+        ];
+        expected_outputs
+            .into_iter()
+            .try_for_each(|(step_count, expected_output)| {
+                let output = part2::count_garden_plots_on_infinite_grid(&grid, step_count)?;
+                assert_eq!(expected_output, output, "after {} steps", step_count);
+                Ok(())
+            })
+    }
+
+    #[test]
+    #[ignore = "slow in debug builds"]
+    fn test_part2_exact_5000_steps() -> Result<(), AocError> {
+        let input = include_str!("../test_input1.txt");
+        let grid = Grid::parse_from(input, part1::parse_tile)?;
+        let output = part2::count_garden_plots_on_infinite_grid(&grid, 5000)?;
+        let expected_output = 16733044;
         assert_eq!(expected_output, output);
         Ok(())
     }
+
+    #[test]
+    fn test_part2_extrapolation() -> Result<(), AocError> {
+        let generator = Generator::with_size(11);
+        (0..5).try_for_each(|seed| {
+            let input = generator.generate_static(seed);
+            let grid = Grid::parse_from(input, part1::parse_tile)?;
+            [55, 60, 71, 87].into_iter().try_for_each(|step_count| {
+                let expected_output =
+                    part2::count_garden_plots_on_infinite_grid(&grid, step_count)?;
+                let output = part2::count_garden_plots_by_extrapolation(&grid, step_count)?;
+                assert_eq!(
+                    expected_output, output,
+                    "seed {}, {} steps",
+                    seed, step_count
+                );
+                Ok(())
+            })
+        })
+    }
 }
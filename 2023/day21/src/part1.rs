@@ -54,23 +54,23 @@ pub fn step_once(
     *current_positions = new_positions;
 }
 
-pub fn count_garden_plots_for_fixed_step_amount(
-    grid: &Grid<Tile>,
-    step_count: usize,
-) -> Result<usize, AocError> {
-    let starting_position = (0..grid.rows)
+pub fn find_start(grid: &Grid<Tile>) -> Option<Position> {
+    (0..grid.rows)
         .find_map(|row_num| {
             grid.row(row_num)
                 .position(|&tile| tile == Tile::Start)
                 .map(|col_num| (row_num, col_num))
         })
-        .map(|(row, col)| Position::new(row, col));
-    if starting_position.is_none() {
-        return Err(AocError::ParseError(
-            "No starting position found".to_string(),
-        ));
-    }
-    let starting_position = starting_position.unwrap();
+        .map(|(row, col)| Position::new(row, col))
+}
+
+pub fn count_garden_plots_for_fixed_step_amount(
+    grid: &Grid<Tile>,
+    step_count: usize,
+) -> Result<usize, AocError> {
+    let starting_position = find_start(grid).ok_or(AocError::ParseError(
+        "No starting position found".to_string(),
+    ))?;
     let mut current_positions: HashSet<Position> = HashSet::from([starting_position]);
     let mut previous_positions: HashSet<Position> = HashSet::new();
     let step_count_mod2 = step_count % 2;
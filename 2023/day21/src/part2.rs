@@ -1,5 +1,160 @@
+use aoclib::assumptions::Assumptions;
+use aoclib::grid::Grid;
 use aoclib::AocError;
 
-pub fn process(input: &'static str) -> Result<i32, AocError> {
-    todo!("Part 2");
+use crate::part1::{count_garden_plots_for_fixed_step_amount, find_start, parse_tile, Tile};
+
+const STEP_COUNT: usize = 26501365;
+/// Number of garden periods after which the reachable plots grow quadratically
+const FIRST_SAMPLED_PERIOD: usize = 2;
+
+fn rocks_in<'a>(tiles: impl Iterator<Item = &'a Tile>) -> Vec<usize> {
+    tiles
+        .enumerate()
+        .filter(|(_, &tile)| tile == Tile::Rocks)
+        .map(|(idx, _)| idx)
+        .collect()
+}
+
+pub fn assumptions() -> Assumptions<Grid<Tile>> {
+    Assumptions::new()
+        .assume("the garden is a square", |grid: &Grid<Tile>| {
+            if grid.rows == grid.cols {
+                Ok(())
+            } else {
+                Err(format!(
+                    "garden has {} rows and {} columns",
+                    grid.rows, grid.cols
+                ))
+            }
+        })
+        .assume(
+            "the start is in the centre",
+            |grid: &Grid<Tile>| match find_start(grid) {
+                Some(start)
+                    if grid.rows % 2 == 1
+                        && grid.cols % 2 == 1
+                        && start.row == grid.rows / 2
+                        && start.col == grid.cols / 2 =>
+                {
+                    Ok(())
+                }
+                Some(start) => Err(format!(
+                    "start at row {}, column {} of a {}x{} garden",
+                    start.row, start.col, grid.rows, grid.cols
+                )),
+                None => Err("no start found".to_string()),
+            },
+        )
+        .assume(
+            "the row and column of the start are free of rocks",
+            |grid: &Grid<Tile>| {
+                let Some(start) = find_start(grid) else {
+                    return Ok(()); // reported by the previous assumption
+                };
+                let rocks_in_row = rocks_in(grid.row(start.row));
+                let rocks_in_col = rocks_in(grid.column(start.col));
+                if rocks_in_row.is_empty() && rocks_in_col.is_empty() {
+                    Ok(())
+                } else {
+                    Err(format!(
+                        "rocks in row {} at columns {:?} and in column {} at rows {:?}",
+                        start.row, rocks_in_row, start.col, rocks_in_col
+                    ))
+                }
+            },
+        )
+        .assume(
+            "the edges of the garden are free of rocks",
+            |grid: &Grid<Tile>| {
+                let edges = [
+                    ("top row", rocks_in(grid.row(0))),
+                    ("bottom row", rocks_in(grid.row(grid.rows - 1))),
+                    ("left column", rocks_in(grid.column(0))),
+                    ("right column", rocks_in(grid.column(grid.cols - 1))),
+                ];
+                edges.into_iter().try_for_each(|(edge, rocks)| {
+                    if rocks.is_empty() {
+                        Ok(())
+                    } else {
+                        Err(format!("rocks in {} at {:?}", edge, rocks))
+                    }
+                })
+            },
+        )
+}
+
+/// Repeats the garden the given number of times in every direction, only the start in the
+/// central copy is kept
+pub fn tiled(grid: &Grid<Tile>, copies_per_side: usize) -> Result<Grid<Tile>, AocError> {
+    let tiles = 2 * copies_per_side + 1;
+    let element_rows = (0..tiles)
+        .flat_map(|tile_row| {
+            (0..grid.rows).map(move |row| {
+                (0..tiles)
+                    .flat_map(|tile_col| {
+                        let is_central_tile =
+                            tile_row == copies_per_side && tile_col == copies_per_side;
+                        grid.row(row).map(move |&tile| match tile {
+                            Tile::Start if !is_central_tile => Tile::Garden,
+                            tile => tile,
+                        })
+                    })
+                    .collect()
+            })
+        })
+        .collect();
+    Grid::new(element_rows)
+}
+
+/// Counts the garden plots reachable in exactly the given number of steps on the infinitely
+/// repeating garden by walking a large enough tiled garden, slow for large step counts
+pub fn count_garden_plots_on_infinite_grid(
+    grid: &Grid<Tile>,
+    step_count: usize,
+) -> Result<usize, AocError> {
+    let copies_per_side = step_count.div_ceil(grid.rows.min(grid.cols));
+    let tiled_grid = tiled(grid, copies_per_side)?;
+    count_garden_plots_for_fixed_step_amount(&tiled_grid, step_count)
+}
+
+/// Counts the garden plots reachable in exactly the given number of steps on the infinitely
+/// repeating garden by extrapolating the counts of a few smaller step counts
+///
+/// The garden has to fulfill the [`assumptions`]: the walk then spreads through the copies of
+/// the garden like a diamond and the reachable plots grow quadratically with every additional
+/// garden period of steps
+pub fn count_garden_plots_by_extrapolation(
+    grid: &Grid<Tile>,
+    step_count: usize,
+) -> Result<usize, AocError> {
+    let period = grid.rows;
+    let remainder = step_count % period;
+    let periods = step_count / period;
+    if periods < FIRST_SAMPLED_PERIOD + 3 {
+        return count_garden_plots_on_infinite_grid(grid, step_count);
+    }
+    let samples = (FIRST_SAMPLED_PERIOD..FIRST_SAMPLED_PERIOD + 3)
+        .map(|sampled_periods| {
+            count_garden_plots_on_infinite_grid(grid, remainder + sampled_periods * period)
+                .map(|count| count as i128)
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    // Newton's forward differences of the quadratic through the samples
+    let first_difference = samples[1] - samples[0];
+    let second_difference = samples[2] - 2 * samples[1] + samples[0];
+    let n = (periods - FIRST_SAMPLED_PERIOD) as i128;
+    let count = samples[0] + n * first_difference + n * (n - 1) / 2 * second_difference;
+    usize::try_from(count).map_err(|_| {
+        AocError::MathError(format!(
+            "Extrapolated negative number of garden plots: {}",
+            count
+        ))
+    })
+}
+
+pub fn process(input: &'static str) -> Result<usize, AocError> {
+    let grid = Grid::parse_from(input, parse_tile)?;
+    assumptions().check(&grid)?;
+    count_garden_plots_by_extrapolation(&grid, STEP_COUNT)
 }
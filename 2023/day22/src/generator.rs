@@ -0,0 +1,59 @@
+use aoclib::generator::{InputGenerator, Rng};
+
+/// Generates snapshots of straight bricks falling in a narrow column
+///
+/// Every brick gets levels of its own in the snapshot so that no two bricks overlap
+#[derive(Debug, Clone)]
+pub struct Generator {
+    pub bricks: usize,
+    /// Width and depth of the column the bricks fall in
+    pub column_size: usize,
+    pub max_brick_length: usize,
+}
+
+impl Default for Generator {
+    fn default() -> Self {
+        Self {
+            bricks: 1200,
+            column_size: 10,
+            max_brick_length: 5,
+        }
+    }
+}
+
+impl InputGenerator for Generator {
+    fn set_size(&mut self, size: usize) {
+        self.bricks = size;
+    }
+
+    fn generate(&self, rng: &mut Rng) -> String {
+        let column_size = self.column_size.max(1);
+        let mut level = 1 + rng.below(3);
+        (0..self.bricks.max(1))
+            .map(|_| {
+                let axis = rng.below(3);
+                let length = rng.between(1, self.max_brick_length.max(1));
+                // keep the brick inside the column
+                let horizontal_starts = [
+                    column_size.saturating_sub(if axis == 0 { length - 1 } else { 0 }),
+                    column_size.saturating_sub(if axis == 1 { length - 1 } else { 0 }),
+                ];
+                let mut start = [
+                    rng.below(horizontal_starts[0].max(1)),
+                    rng.below(horizontal_starts[1].max(1)),
+                    level,
+                ];
+                let mut end = start;
+                end[axis] += length - 1;
+                level = end[2] + 1 + rng.below(3);
+                if rng.chance(0.5) {
+                    std::mem::swap(&mut start, &mut end);
+                }
+                format!(
+                    "{},{},{}~{},{},{}\n",
+                    start[0], start[1], start[2], end[0], end[1], end[2]
+                )
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,74 @@
+pub mod generator;
+pub mod parsing;
+pub mod part1;
+pub mod part2;
+pub mod stack;
+
+#[cfg(test)]
+mod tests {
+    use crate::generator::Generator;
+    use crate::parsing::parse_bricks;
+    use crate::part1;
+    use crate::part2;
+    use crate::stack::Stack;
+    use aoclib::generator::{check_generated_inputs, InputGenerator};
+    use aoclib::AocError;
+
+    #[test]
+    fn test_part1() -> Result<(), AocError> {
+        let input = include_str!("../test_input1.txt");
+        if input.is_empty() {
+            return Err(AocError::ParseError(
+                "Input file for test 1 is empty".to_string(),
+            ));
+        }
+        let output = part1::process(input)?;
+        let expected_output = 5;
+        assert_eq!(expected_output, output);
+        Ok(())
+    }
+
+    #[test]
+    fn test_part2() -> Result<(), AocError> {
+        let input = include_str!("../test_input2.txt");
+        if input.is_empty() {
+            return Err(AocError::ParseError(
+                "Input file for test 2 is empty".to_string(),
+            ));
+        }
+        let output = part2::process(input)?;
+        let expected_output = 7;
+        assert_eq!(expected_output, output);
+        Ok(())
+    }
+
+    #[test]
+    fn test_settled_stack() -> Result<(), AocError> {
+        let input = include_str!("../test_input1.txt");
+        let stack = Stack::settle(parse_bricks(input)?)?;
+        let lowest_levels: Vec<_> = stack.bricks.iter().map(|brick| brick.min.z).collect();
+        assert_eq!(vec![1, 2, 2, 3, 3, 4, 5], lowest_levels);
+        assert_eq!(vec![1, 2], stack.supports[0]);
+        assert_eq!(vec![3, 4], stack.supported_by[5]);
+        assert_eq!(6, stack.chain_reaction(0));
+        assert_eq!(1, stack.chain_reaction(5));
+        Ok(())
+    }
+
+    #[test]
+    fn test_generated_inputs() -> Result<(), AocError> {
+        check_generated_inputs(&Generator::with_size(30), |input| {
+            let stack = Stack::settle(parse_bricks(input)?)?;
+            // settled bricks neither overlap nor float
+            stack.bricks.iter().enumerate().for_each(|(idx, brick)| {
+                assert!(stack.bricks[idx + 1..]
+                    .iter()
+                    .all(|other| !brick.overlaps(other)));
+                assert!(brick.min.z == 1 || !stack.supported_by[idx].is_empty());
+            });
+            part1::process(input)?;
+            part2::process(input)?;
+            Ok(())
+        })
+    }
+}
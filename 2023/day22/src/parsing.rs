@@ -0,0 +1,27 @@
+use aoclib::cuboid::Cuboid;
+use aoclib::point3::Point3;
+use aoclib::AocError;
+use nom::{
+    character::complete::{char as char_parser, line_ending},
+    multi::many1,
+    sequence::{separated_pair, terminated},
+    IResult,
+};
+
+fn parse_brick(input: &str) -> IResult<&str, Cuboid> {
+    let (input, (corner, opposite_corner)) =
+        separated_pair(Point3::parse, char_parser('~'), Point3::parse)(input)?;
+    Ok((input, Cuboid::new(corner, opposite_corner)))
+}
+
+pub fn parse_bricks(input: &str) -> Result<Vec<Cuboid>, AocError> {
+    let (rest, bricks) = many1(terminated(parse_brick, line_ending))(input)?;
+    if rest.is_empty() {
+        Ok(bricks)
+    } else {
+        Err(AocError::ParseError(format!(
+            "Parsing bricks left rest: {}",
+            rest
+        )))
+    }
+}
@@ -0,0 +1,12 @@
+use aoclib::AocError;
+
+use crate::parsing::parse_bricks;
+use crate::stack::Stack;
+
+pub fn process(input: &'static str) -> Result<usize, AocError> {
+    let stack = Stack::settle(parse_bricks(input)?)?;
+    let safe_bricks = (0..stack.bricks.len())
+        .filter(|&idx| stack.is_safe_to_disintegrate(idx))
+        .count();
+    Ok(safe_bricks)
+}
@@ -0,0 +1,94 @@
+use std::collections::HashMap;
+
+use aoclib::cuboid::Cuboid;
+use aoclib::point3::Point3;
+use aoclib::AocError;
+
+const GROUND_LEVEL: i64 = 0;
+
+/// Bricks after they settled under gravity together with which bricks rest on which
+#[derive(Debug, Clone)]
+pub struct Stack {
+    pub bricks: Vec<Cuboid>,
+    /// Indices of the bricks directly resting on each brick
+    pub supports: Vec<Vec<usize>>,
+    /// Indices of the bricks each brick directly rests on
+    pub supported_by: Vec<Vec<usize>>,
+}
+
+impl Stack {
+    /// Lets the bricks fall from lowest to highest, each one stops on the highest brick below
+    /// it or the ground
+    pub fn settle(mut bricks: Vec<Cuboid>) -> Result<Self, AocError> {
+        if let Some(brick) = bricks.iter().find(|brick| brick.min.z <= GROUND_LEVEL) {
+            return Err(AocError::LogicError(format!(
+                "Brick {:?} is not above the ground",
+                brick
+            )));
+        }
+        bricks.sort_by_key(|brick| brick.min.z);
+        // highest occupied level and the brick occupying it for every (x, y) column
+        let mut height_map: HashMap<(i64, i64), (i64, usize)> = HashMap::new();
+        let mut supports = vec![Vec::new(); bricks.len()];
+        let mut supported_by = vec![Vec::new(); bricks.len()];
+        let mut settled_bricks = Vec::with_capacity(bricks.len());
+        for (idx, brick) in bricks.iter().enumerate() {
+            let below: Vec<(i64, usize)> = brick
+                .footprint()
+                .filter_map(|column| height_map.get(&column).copied())
+                .collect();
+            let resting_level = below
+                .iter()
+                .map(|&(height, _)| height)
+                .max()
+                .unwrap_or(GROUND_LEVEL);
+            let mut supporters: Vec<usize> = below
+                .into_iter()
+                .filter(|&(height, _)| height == resting_level)
+                .map(|(_, supporter)| supporter)
+                .collect();
+            supporters.sort();
+            supporters.dedup();
+            supporters
+                .iter()
+                .for_each(|&supporter| supports[supporter].push(idx));
+            supported_by[idx] = supporters;
+            let settled_brick =
+                brick.translated(Point3::new(0, 0, resting_level + 1 - brick.min.z));
+            settled_brick.footprint().for_each(|column| {
+                height_map.insert(column, (settled_brick.max.z, idx));
+            });
+            settled_bricks.push(settled_brick);
+        }
+        Ok(Self {
+            bricks: settled_bricks,
+            supports,
+            supported_by,
+        })
+    }
+
+    /// Checks whether every brick resting on the given brick rests on another brick as well
+    pub fn is_safe_to_disintegrate(&self, idx: usize) -> bool {
+        self.supports[idx]
+            .iter()
+            .all(|&supported| self.supported_by[supported].len() > 1)
+    }
+
+    /// Counts the other bricks that fall when the given brick is disintegrated
+    pub fn chain_reaction(&self, idx: usize) -> usize {
+        let mut fallen = vec![false; self.bricks.len()];
+        fallen[idx] = true;
+        // bricks are settled in ascending order of their lowest level, so every brick that could
+        // fall rests on bricks with lower indices only
+        (idx + 1..self.bricks.len())
+            .filter(|&candidate| {
+                let falls = !self.supported_by[candidate].is_empty()
+                    && self.supported_by[candidate]
+                        .iter()
+                        .all(|&supporter| fallen[supporter]);
+                fallen[candidate] = falls;
+                falls
+            })
+            .count()
+    }
+}
@@ -0,0 +1,147 @@
+use std::collections::VecDeque;
+
+use aoclib::generator::{InputGenerator, Rng};
+
+/// Generates hiking trail maps from a random maze with some additional openings and without
+/// dead ends
+///
+/// Slopes are placed next to the junctions and point away from the start, so every junction
+/// can still be reached when hiking downhill only
+#[derive(Debug, Clone)]
+pub struct Generator {
+    /// Side length of the map, rounded up to the next odd number
+    pub side_length: usize,
+    /// Probability of a wall between two maze cells to be removed after carving the maze
+    pub opening_chance: f64,
+}
+
+impl Default for Generator {
+    fn default() -> Self {
+        Self {
+            side_length: 41,
+            opening_chance: 0.05,
+        }
+    }
+}
+
+/// Offsets of the neighbours with the slope leading to them, opposite neighbours are two apart
+const NEIGHBOURS: [(isize, isize, char); 4] =
+    [(-1, 0, '^'), (0, 1, '>'), (1, 0, 'v'), (0, -1, '<')];
+
+fn neighbour(
+    side_length: usize,
+    (row, col): (usize, usize),
+    (dr, dc): (isize, isize),
+) -> Option<(usize, usize)> {
+    let row = row.checked_add_signed(dr)?;
+    let col = col.checked_add_signed(dc)?;
+    (row < side_length && col < side_length).then_some((row, col))
+}
+
+impl InputGenerator for Generator {
+    fn set_size(&mut self, size: usize) {
+        self.side_length = size;
+    }
+
+    fn generate(&self, rng: &mut Rng) -> String {
+        let side_length = self.side_length.max(5) | 1;
+        let mut map = vec![vec!['#'; side_length]; side_length];
+        // carve a maze through the cells at odd coordinates with a randomised depth first search
+        let mut stack = vec![(1, 1)];
+        map[1][1] = '.';
+        while let Some(&cell) = stack.last() {
+            let mut unvisited: Vec<_> = NEIGHBOURS
+                .iter()
+                .filter_map(|&(dr, dc, _)| {
+                    let next = neighbour(side_length, cell, (2 * dr, 2 * dc))?;
+                    (next.0 < side_length - 1
+                        && next.1 < side_length - 1
+                        && map[next.0][next.1] == '#')
+                        .then_some((next, dr, dc))
+                })
+                .collect();
+            if unvisited.is_empty() {
+                stack.pop();
+                continue;
+            }
+            rng.shuffle(&mut unvisited);
+            let (next, dr, dc) = unvisited[0];
+            map[(cell.0 as isize + dr) as usize][(cell.1 as isize + dc) as usize] = '.';
+            map[next.0][next.1] = '.';
+            stack.push(next);
+        }
+        // open additional walls between cells to create loops
+        (1..side_length - 1).for_each(|row| {
+            (1..side_length - 1).for_each(|col| {
+                if (row + col) % 2 == 1 && rng.chance(self.opening_chance) {
+                    map[row][col] = '.';
+                }
+            })
+        });
+        map[0][1] = '.';
+        map[side_length - 1][side_length - 2] = '.';
+        let open_neighbours =
+            |map: &Vec<Vec<char>>, cell: (usize, usize)| -> Vec<(usize, usize, char, char)> {
+                NEIGHBOURS
+                    .iter()
+                    .enumerate()
+                    .filter_map(|(idx, &(dr, dc, slope))| {
+                        let opposite_slope = NEIGHBOURS[(idx + 2) % 4].2;
+                        let (row, col) = neighbour(side_length, cell, (dr, dc))?;
+                        (map[row][col] != '#').then_some((row, col, slope, opposite_slope))
+                    })
+                    .collect()
+            };
+        // real trail maps have no dead ends, fill them until only loops and the way through remain
+        let mut dead_ends: Vec<(usize, usize)> = (1..side_length - 1)
+            .flat_map(|row| (1..side_length - 1).map(move |col| (row, col)))
+            .collect();
+        while let Some(cell) = dead_ends.pop() {
+            if map[cell.0][cell.1] == '.' && open_neighbours(&map, cell).len() <= 1 {
+                map[cell.0][cell.1] = '#';
+                dead_ends.extend(
+                    open_neighbours(&map, cell)
+                        .into_iter()
+                        .map(|(row, col, _, _)| (row, col))
+                        .filter(|&(row, _)| row > 0 && row < side_length - 1),
+                );
+            }
+        }
+
+        let mut distances = vec![vec![usize::MAX; side_length]; side_length];
+        distances[0][1] = 0;
+        let mut queue = VecDeque::from([(0, 1)]);
+        while let Some(cell) = queue.pop_front() {
+            open_neighbours(&map, cell)
+                .into_iter()
+                .for_each(|(row, col, _, _)| {
+                    if distances[row][col] == usize::MAX {
+                        distances[row][col] = distances[cell.0][cell.1] + 1;
+                        queue.push_back((row, col));
+                    }
+                });
+        }
+        let junctions: Vec<(usize, usize)> = (0..side_length)
+            .flat_map(|row| (0..side_length).map(move |col| (row, col)))
+            .filter(|&cell| map[cell.0][cell.1] != '#' && open_neighbours(&map, cell).len() >= 3)
+            .collect();
+        junctions.iter().for_each(|&junction| {
+            open_neighbours(&map, junction).into_iter().for_each(
+                |(row, col, slope, opposite_slope)| {
+                    // the entrance and the exit stay paths
+                    if row == 0 || row == side_length - 1 || junctions.contains(&(row, col)) {
+                        return;
+                    }
+                    map[row][col] = if distances[row][col] > distances[junction.0][junction.1] {
+                        slope
+                    } else {
+                        opposite_slope
+                    };
+                },
+            )
+        });
+        map.into_iter()
+            .map(|row| row.into_iter().collect::<String>() + "\n")
+            .collect()
+    }
+}
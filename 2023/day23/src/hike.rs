@@ -0,0 +1,53 @@
+use aoclib::junction_graph::JunctionGraph;
+use aoclib::AocError;
+
+struct Hike<'a> {
+    graph: &'a JunctionGraph,
+    end: usize,
+    /// The only junction leading to the end, if there is one, must go to the end directly as
+    /// the end cannot be reached anymore after leaving it in any other direction
+    last_junction: Option<usize>,
+}
+
+impl Hike<'_> {
+    fn longest_from(&self, junction: usize, visited: u64) -> Option<usize> {
+        if junction == self.end {
+            return Some(0);
+        }
+        self.graph.edges[junction]
+            .iter()
+            .filter(|&&(next, _)| visited & (1 << next) == 0)
+            .filter(|&&(next, _)| self.last_junction != Some(junction) || next == self.end)
+            .filter_map(|&(next, length)| {
+                self.longest_from(next, visited | (1 << next))
+                    .map(|rest| rest + length)
+            })
+            .max()
+    }
+}
+
+/// Finds the length of the longest path from the start to the end junction that visits every
+/// junction at most once by trying all of them
+pub fn longest_hike(graph: &JunctionGraph, start: usize, end: usize) -> Result<usize, AocError> {
+    if graph.junctions.len() > u64::BITS as usize {
+        return Err(AocError::LogicError(format!(
+            "Cannot track {} junctions in a bitmask",
+            graph.junctions.len()
+        )));
+    }
+    let junctions_to_end: Vec<usize> = (0..graph.junctions.len())
+        .filter(|&junction| graph.edges[junction].iter().any(|&(next, _)| next == end))
+        .collect();
+    let hike = Hike {
+        graph,
+        end,
+        last_junction: match junctions_to_end[..] {
+            [last_junction] => Some(last_junction),
+            _ => None,
+        },
+    };
+    hike.longest_from(start, 1 << start)
+        .ok_or(AocError::LogicError(
+            "The end cannot be reached".to_string(),
+        ))
+}
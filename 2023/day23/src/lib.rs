@@ -0,0 +1,63 @@
+pub mod generator;
+mod hike;
+pub mod part1;
+pub mod part2;
+#[cfg(any(test, feature = "reference"))]
+pub mod reference;
+
+#[cfg(test)]
+mod tests {
+    use crate::generator::Generator;
+    use crate::part1;
+    use crate::part2;
+    use crate::reference;
+    use aoclib::generator::check_generated_inputs;
+    use aoclib::AocError;
+
+    #[test]
+    fn test_part1() -> Result<(), AocError> {
+        let input = include_str!("../test_input1.txt");
+        if input.is_empty() {
+            return Err(AocError::ParseError(
+                "Input file for test 1 is empty".to_string(),
+            ));
+        }
+        let output = part1::process(input)?;
+        let expected_output = 94;
+        assert_eq!(expected_output, output);
+        Ok(())
+    }
+
+    #[test]
+    fn test_part2() -> Result<(), AocError> {
+        let input = include_str!("../test_input2.txt");
+        if input.is_empty() {
+            return Err(AocError::ParseError(
+                "Input file for test 2 is empty".to_string(),
+            ));
+        }
+        let output = part2::process(input)?;
+        let expected_output = 154;
+        assert_eq!(expected_output, output);
+        Ok(())
+    }
+
+    #[test]
+    fn test_generated_inputs_match_reference() -> Result<(), AocError> {
+        let generator = Generator {
+            side_length: 11,
+            opening_chance: 0.2,
+        };
+        check_generated_inputs(&generator, |input| {
+            assert_eq!(
+                reference::longest_hike(input, false)?,
+                part1::process(input)?
+            );
+            assert_eq!(
+                reference::longest_hike(input, true)?,
+                part2::process(input)?
+            );
+            Ok(())
+        })
+    }
+}
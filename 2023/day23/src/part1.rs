@@ -0,0 +1,73 @@
+use aoclib::direction::Direction;
+use aoclib::grid::Grid;
+use aoclib::junction_graph::JunctionGraph;
+use aoclib::position::Position;
+use aoclib::AocError;
+use nom::{branch::alt, character::complete::char as char_parser, combinator::value, IResult};
+
+use crate::hike::longest_hike;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Tile {
+    Path,
+    Forest,
+    Slope(Direction),
+}
+
+impl From<&Tile> for char {
+    fn from(tile: &Tile) -> char {
+        match tile {
+            Tile::Path => '.',
+            Tile::Forest => '#',
+            Tile::Slope(Direction::Up) => '^',
+            Tile::Slope(Direction::Right) => '>',
+            Tile::Slope(Direction::Down) => 'v',
+            Tile::Slope(Direction::Left) => '<',
+        }
+    }
+}
+
+pub fn parse_tile(input: &str) -> IResult<&str, Tile> {
+    alt((
+        value(Tile::Path, char_parser('.')),
+        value(Tile::Forest, char_parser('#')),
+        value(Tile::Slope(Direction::Up), char_parser('^')),
+        value(Tile::Slope(Direction::Right), char_parser('>')),
+        value(Tile::Slope(Direction::Down), char_parser('v')),
+        value(Tile::Slope(Direction::Left), char_parser('<')),
+    ))(input)
+}
+
+/// Slopes are too icy to climb, from a slope the only way is downhill
+fn can_step_downhill(from: &Tile, direction: Direction, to: &Tile) -> bool {
+    match (from, to) {
+        (_, Tile::Forest) | (Tile::Forest, _) => false,
+        (Tile::Slope(downhill), _) => *downhill == direction,
+        (Tile::Path, _) => true,
+    }
+}
+
+fn path_in_row(grid: &Grid<Tile>, row: usize) -> Result<Position, AocError> {
+    grid.row(row)
+        .position(|&tile| tile == Tile::Path)
+        .map(|col| Position::new(row, col))
+        .ok_or(AocError::ParseError(format!("No path in row {}", row)))
+}
+
+/// Contracts the trails of the map and returns the junction graph with the indices of the
+/// start in the top row and the end in the bottom row
+pub fn trail_graph(
+    grid: &Grid<Tile>,
+    can_step: impl Fn(&Tile, Direction, &Tile) -> bool,
+) -> Result<(JunctionGraph, usize, usize), AocError> {
+    let start = path_in_row(grid, 0)?;
+    let end = path_in_row(grid, grid.rows - 1)?;
+    let graph = JunctionGraph::contract(grid, &[start, end], can_step);
+    Ok((graph, 0, 1))
+}
+
+pub fn process(input: &'static str) -> Result<usize, AocError> {
+    let grid = Grid::parse_from(input, parse_tile)?;
+    let (graph, start, end) = trail_graph(&grid, can_step_downhill)?;
+    longest_hike(&graph, start, end)
+}
@@ -0,0 +1,17 @@
+use aoclib::direction::Direction;
+use aoclib::grid::Grid;
+use aoclib::AocError;
+
+use crate::hike::longest_hike;
+use crate::part1::{parse_tile, trail_graph, Tile};
+
+/// Slopes are just paths when they are dry
+fn can_step_ignoring_slopes(from: &Tile, _: Direction, to: &Tile) -> bool {
+    *from != Tile::Forest && *to != Tile::Forest
+}
+
+pub fn process(input: &'static str) -> Result<usize, AocError> {
+    let grid = Grid::parse_from(input, parse_tile)?;
+    let (graph, start, end) = trail_graph(&grid, can_step_ignoring_slopes)?;
+    longest_hike(&graph, start, end)
+}
@@ -0,0 +1,55 @@
+use std::collections::HashSet;
+
+use aoclib::direction::Direction;
+use aoclib::grid::Grid;
+use aoclib::position::Position;
+use aoclib::AocError;
+
+use crate::part1::{parse_tile, Tile};
+
+struct Walk<'a> {
+    grid: &'a Grid<Tile>,
+    end: Position,
+    ignore_slopes: bool,
+}
+
+impl Walk<'_> {
+    fn longest_from(&self, pos: Position, visited: &mut HashSet<Position>) -> Option<usize> {
+        if pos == self.end {
+            return Some(0);
+        }
+        let directions: Vec<Direction> = match self.grid.get_pos(&pos) {
+            Some(Tile::Slope(downhill)) if !self.ignore_slopes => vec![*downhill],
+            _ => Direction::each().collect(),
+        };
+        directions
+            .into_iter()
+            .filter_map(|direction| pos.in_direction(direction))
+            .filter(|next| matches!(self.grid.get_pos(next), Some(tile) if *tile != Tile::Forest))
+            .filter_map(|next| {
+                if !visited.insert(next) {
+                    return None;
+                }
+                let length = self.longest_from(next, visited).map(|rest| rest + 1);
+                visited.remove(&next);
+                length
+            })
+            .max()
+    }
+}
+
+/// Walks every hike step by step instead of contracting the trails, only feasible for tiny maps
+pub fn longest_hike(input: &'static str, ignore_slopes: bool) -> Result<usize, AocError> {
+    let grid = Grid::parse_from(input, parse_tile)?;
+    let start = Position::new(0, 1);
+    let end = Position::new(grid.rows - 1, grid.cols - 2);
+    let walk = Walk {
+        grid: &grid,
+        end,
+        ignore_slopes,
+    };
+    walk.longest_from(start, &mut HashSet::from([start]))
+        .ok_or(AocError::LogicError(
+            "The end cannot be reached".to_string(),
+        ))
+}
@@ -0,0 +1,72 @@
+use aoclib::generator::{InputGenerator, Rng};
+
+/// Generates hailstones that are all hit by a single rock thrown from integer coordinates
+/// with an integer velocity
+///
+/// Every hailstone gets its own collision time, the hailstone positions are derived from the
+/// rock's path at that time
+#[derive(Debug, Clone)]
+pub struct Generator {
+    pub hailstones: usize,
+    /// Upper bound of the absolute value of every velocity component
+    pub max_speed: usize,
+    /// Upper bound of the collision times
+    pub max_time: usize,
+}
+
+impl Default for Generator {
+    fn default() -> Self {
+        Self {
+            hailstones: 300,
+            max_speed: 300,
+            max_time: 1_000_000_000_000,
+        }
+    }
+}
+
+const AREA_CENTER: i64 = 300000000000000;
+const AREA_RADIUS: usize = 100000000000000;
+
+fn offset(rng: &mut Rng, max: usize) -> i64 {
+    rng.below(2 * max + 1) as i64 - max as i64
+}
+
+impl InputGenerator for Generator {
+    fn set_size(&mut self, size: usize) {
+        self.hailstones = size;
+    }
+
+    fn generate(&self, rng: &mut Rng) -> String {
+        let max_speed = self.max_speed.max(1);
+        let rock_position: Vec<i64> = (0..3)
+            .map(|_| AREA_CENTER + offset(rng, AREA_RADIUS))
+            .collect();
+        let rock_velocity: Vec<i64> = (0..3).map(|_| offset(rng, max_speed)).collect();
+        let mut times: Vec<i64> = Vec::new();
+        while times.len() < self.hailstones.max(3) {
+            let time = rng.between(1, self.max_time.max(self.hailstones)) as i64;
+            if !times.contains(&time) {
+                times.push(time);
+            }
+        }
+        times
+            .into_iter()
+            .map(|time| {
+                let velocity = loop {
+                    let velocity: Vec<i64> = (0..3).map(|_| offset(rng, max_speed)).collect();
+                    // a hailstone flying alongside the rock would never be hit by it
+                    if velocity != rock_velocity {
+                        break velocity;
+                    }
+                };
+                let position: Vec<i64> = (0..3)
+                    .map(|axis| rock_position[axis] + time * (rock_velocity[axis] - velocity[axis]))
+                    .collect();
+                format!(
+                    "{}, {}, {} @ {}, {}, {}\n",
+                    position[0], position[1], position[2], velocity[0], velocity[1], velocity[2]
+                )
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,39 @@
+use aoclib::linear_algebra::solve;
+use aoclib::point3::Point3;
+use aoclib::rational::Rational;
+use aoclib::AocError;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Hailstone {
+    pub position: Point3,
+    pub velocity: Point3,
+}
+
+impl Hailstone {
+    /// Returns the point where the paths of both hailstones cross in the xy plane, ignoring
+    /// the z axis and the time each of them gets there
+    ///
+    /// Returns `None` if the paths are parallel or crossed in the past of either hailstone
+    pub fn crossing_xy(&self, other: &Hailstone) -> Result<Option<(Rational, Rational)>, AocError> {
+        let (v1, v2) = (self.velocity, other.velocity);
+        if v1.x as i128 * v2.y as i128 == v1.y as i128 * v2.x as i128 {
+            return Ok(None);
+        }
+        // position + t1 * velocity == other.position + t2 * other.velocity
+        let r = |value: i64| Rational::from(value as i128);
+        let matrix = vec![vec![r(v1.x), -r(v2.x)], vec![r(v1.y), -r(v2.y)]];
+        // far apart positions do not fit the difference into an i64
+        let offset = |other: i64, own: i64| Rational::from(other as i128 - own as i128);
+        let offset = [
+            offset(other.position.x, self.position.x),
+            offset(other.position.y, self.position.y),
+        ];
+        let times = solve(&matrix, &offset)?;
+        if times.iter().any(|time| *time < Rational::zero()) {
+            return Ok(None);
+        }
+        let x = r(self.position.x).checked_add(&times[0].checked_mul(&r(v1.x))?)?;
+        let y = r(self.position.y).checked_add(&times[0].checked_mul(&r(v1.y))?)?;
+        Ok(Some((x, y)))
+    }
+}
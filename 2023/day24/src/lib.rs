@@ -0,0 +1,136 @@
+pub mod generator;
+pub mod hailstone;
+pub mod parsing;
+pub mod part1;
+pub mod part2;
+
+#[cfg(test)]
+mod tests {
+    use crate::generator::Generator;
+    use crate::hailstone::Hailstone;
+    use crate::parsing::parse_hailstones;
+    use crate::part1;
+    use crate::part2;
+    use aoclib::generator::{check_generated_inputs, InputGenerator};
+    use aoclib::point3::Point3;
+    use aoclib::rational::Rational;
+    use aoclib::AocError;
+
+    #[test]
+    fn test_part1() -> Result<(), AocError> {
+        let input = include_str!("../test_input1.txt");
+        if input.is_empty() {
+            return Err(AocError::ParseError(
+                "Input file for test 1 is empty".to_string(),
+            ));
+        }
+        let hailstones = parse_hailstones(input)?;
+        let output = part1::count_crossings_in_area(&hailstones, 7, 27)?;
+        let expected_output = 2;
+        assert_eq!(expected_output, output);
+        Ok(())
+    }
+
+    #[test]
+    fn test_part2() -> Result<(), AocError> {
+        let input = include_str!("../test_input2.txt");
+        if input.is_empty() {
+            return Err(AocError::ParseError(
+                "Input file for test 2 is empty".to_string(),
+            ));
+        }
+        let output = part2::process(input)?;
+        let expected_output = 47;
+        assert_eq!(expected_output, output);
+        Ok(())
+    }
+
+    #[test]
+    fn test_crossings() -> Result<(), AocError> {
+        let hailstones = parse_hailstones(include_str!("../test_input1.txt"))?;
+        let r = |n: i128| Rational::from(n);
+        let (x, y) = hailstones[0].crossing_xy(&hailstones[1])?.unwrap();
+        assert_eq!((r(43) / r(3), r(46) / r(3)), (x, y));
+        // parallel paths
+        assert_eq!(None, hailstones[1].crossing_xy(&hailstones[2])?);
+        // crossed in the past of the first hailstone
+        assert_eq!(None, hailstones[0].crossing_xy(&hailstones[4])?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_degenerate_throw() {
+        let hailstone = |position, velocity| Hailstone { position, velocity };
+        let a = hailstone(Point3::new(0, 0, 0), Point3::new(1, 0, 0));
+        let b = hailstone(Point3::new(0, 1, 0), Point3::new(1, 0, 0));
+        let c = hailstone(Point3::new(0, 2, 0), Point3::new(1, 0, 0));
+        // all hailstones move in a single plane, so there are infinitely many throws
+        assert!(matches!(
+            part2::throw_rock([&a, &b, &c]),
+            Err(AocError::MathError(_))
+        ));
+    }
+
+    #[test]
+    fn test_overflowing_throw() {
+        let hailstone = |position, velocity| Hailstone { position, velocity };
+        let far = 9_000_000_000_000_000_000;
+        let a = hailstone(Point3::new(far, -far, 0), Point3::new(-far, -far, 0));
+        let b = hailstone(Point3::new(-far, far, 0), Point3::new(far, far, 1));
+        let c = hailstone(Point3::new(1, 2, 3), Point3::new(4, 5, 6));
+        assert!(matches!(
+            part2::throw_rock([&a, &b, &c]),
+            Err(AocError::MathError(_))
+        ));
+    }
+
+    #[test]
+    fn test_crossing_far_apart() -> Result<(), AocError> {
+        let hailstone = |position, velocity| Hailstone { position, velocity };
+        let a = hailstone(Point3::new(i64::MIN, 0, 0), Point3::new(1, 1, 0));
+        let b = hailstone(Point3::new(i64::MAX, 0, 0), Point3::new(-1, 1, 0));
+        let expected = (
+            Rational::from(-1) / Rational::from(2),
+            Rational::from(i64::MAX as i128) + Rational::from(1) / Rational::from(2),
+        );
+        assert_eq!(Some(expected), a.crossing_xy(&b)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_generated_inputs() -> Result<(), AocError> {
+        check_generated_inputs(&Generator::with_size(20), |input| {
+            let hailstones = parse_hailstones(input)?;
+            part1::process(input)?;
+            part2::process(input)?;
+            let (positions, velocities) =
+                part2::throw_rock([&hailstones[0], &hailstones[1], &hailstones[2]])?;
+            hailstones.iter().for_each(|hailstone| {
+                let r = |n: i64| Rational::from(n as i128);
+                let hailstone_position = [
+                    hailstone.position.x,
+                    hailstone.position.y,
+                    hailstone.position.z,
+                ];
+                let hailstone_velocity = [
+                    hailstone.velocity.x,
+                    hailstone.velocity.y,
+                    hailstone.velocity.z,
+                ];
+                // the rock hits the hailstone at the same time on every axis
+                let times: Vec<_> = (0..3)
+                    .filter(|&axis| velocities[axis] != r(hailstone_velocity[axis]))
+                    .map(|axis| {
+                        (r(hailstone_position[axis]) - positions[axis])
+                            / (velocities[axis] - r(hailstone_velocity[axis]))
+                    })
+                    .collect();
+                assert!(!times.is_empty());
+                assert!(times
+                    .iter()
+                    .all(|time| *time == times[0] && *time > Rational::zero()));
+            });
+            Ok(())
+        })
+    }
+}
@@ -0,0 +1,43 @@
+use aoclib::point3::Point3;
+use aoclib::AocError;
+use nom::{
+    character::complete::{char as char_parser, i64 as i64_parser, line_ending, space0},
+    multi::many1,
+    sequence::{delimited, separated_pair, terminated, tuple},
+    IResult,
+};
+
+use crate::hailstone::Hailstone;
+
+fn separator<'a>(symbol: char) -> impl FnMut(&'a str) -> IResult<&'a str, char> {
+    delimited(space0, char_parser(symbol), space0)
+}
+
+fn parse_vector(input: &str) -> IResult<&str, Point3> {
+    let (input, (x, _, y, _, z)) = tuple((
+        i64_parser,
+        separator(','),
+        i64_parser,
+        separator(','),
+        i64_parser,
+    ))(input)?;
+    Ok((input, Point3::new(x, y, z)))
+}
+
+fn parse_hailstone(input: &str) -> IResult<&str, Hailstone> {
+    let (input, (position, velocity)) =
+        separated_pair(parse_vector, separator('@'), parse_vector)(input)?;
+    Ok((input, Hailstone { position, velocity }))
+}
+
+pub fn parse_hailstones(input: &str) -> Result<Vec<Hailstone>, AocError> {
+    let (rest, hailstones) = many1(terminated(parse_hailstone, line_ending))(input)?;
+    if rest.is_empty() {
+        Ok(hailstones)
+    } else {
+        Err(AocError::ParseError(format!(
+            "Parsing hailstones left rest: {}",
+            rest
+        )))
+    }
+}
@@ -0,0 +1,37 @@
+use aoclib::rational::Rational;
+use aoclib::AocError;
+
+use crate::hailstone::Hailstone;
+use crate::parsing::parse_hailstones;
+
+const TEST_AREA_MIN: i128 = 200000000000000;
+const TEST_AREA_MAX: i128 = 400000000000000;
+
+/// Counts the pairs of hailstones whose future paths cross within `min..=max` on both the x
+/// and the y axis
+pub fn count_crossings_in_area(
+    hailstones: &[Hailstone],
+    min: i128,
+    max: i128,
+) -> Result<usize, AocError> {
+    let area = Rational::from(min)..=Rational::from(max);
+    hailstones
+        .iter()
+        .enumerate()
+        .flat_map(|(idx, hailstone)| {
+            hailstones[idx + 1..]
+                .iter()
+                .map(move |other| hailstone.crossing_xy(other))
+        })
+        .try_fold(0, |count, crossing| {
+            Ok(match crossing? {
+                Some((x, y)) if area.contains(&x) && area.contains(&y) => count + 1,
+                _ => count,
+            })
+        })
+}
+
+pub fn process(input: &'static str) -> Result<usize, AocError> {
+    let hailstones = parse_hailstones(input)?;
+    count_crossings_in_area(&hailstones, TEST_AREA_MIN, TEST_AREA_MAX)
+}
@@ -0,0 +1,132 @@
+use aoclib::linear_algebra::solve;
+use aoclib::point3::Point3;
+use aoclib::rational::Rational;
+use aoclib::AocError;
+
+use crate::hailstone::Hailstone;
+use crate::parsing::parse_hailstones;
+
+type Vector = [i128; 3];
+
+fn vector(point: Point3) -> Vector {
+    [point.x as i128, point.y as i128, point.z as i128]
+}
+
+fn difference(a: Vector, b: Vector) -> Vector {
+    [a[0] - b[0], a[1] - b[1], a[2] - b[2]]
+}
+
+fn checked_mul(a: i128, b: i128) -> Result<i128, AocError> {
+    a.checked_mul(b).ok_or(AocError::MathError(format!(
+        "Overflow when calculating {} * {}",
+        a, b
+    )))
+}
+
+fn checked_add(a: i128, b: i128) -> Result<i128, AocError> {
+    a.checked_add(b).ok_or(AocError::MathError(format!(
+        "Overflow when calculating {} + {}",
+        a, b
+    )))
+}
+
+fn checked_sub(a: i128, b: i128) -> Result<i128, AocError> {
+    a.checked_sub(b).ok_or(AocError::MathError(format!(
+        "Overflow when calculating {} - {}",
+        a, b
+    )))
+}
+
+fn cross(a: Vector, b: Vector) -> Result<Vector, AocError> {
+    let component =
+        |i: usize, j: usize| checked_sub(checked_mul(a[i], b[j])?, checked_mul(a[j], b[i])?);
+    Ok([component(1, 2)?, component(2, 0)?, component(0, 1)?])
+}
+
+fn dot(a: Vector, b: Vector) -> Result<i128, AocError> {
+    a.into_iter()
+        .zip(b)
+        .try_fold(0, |sum, (x, y)| checked_add(sum, checked_mul(x, y)?))
+}
+
+/// Time at which the rock hits `target`, given that it also hits `reference` and `other`
+///
+/// Seen from `reference` the rock flies through its origin, so its path lies in the plane
+/// spanned by the path of `other` and the origin, and `target` has to cross that plane
+fn hit_time(
+    reference: &Hailstone,
+    other: &Hailstone,
+    target: &Hailstone,
+) -> Result<Rational, AocError> {
+    let (origin, drift) = (vector(reference.position), vector(reference.velocity));
+    let normal = cross(
+        difference(vector(other.position), origin),
+        difference(vector(other.velocity), drift),
+    )?;
+    Rational::new(
+        checked_sub(0, dot(difference(vector(target.position), origin), normal)?)?,
+        dot(difference(vector(target.velocity), drift), normal)?,
+    )
+}
+
+/// Finds the position and velocity of the rock thrown so that it hits all three hailstones
+pub fn throw_rock(hailstones: [&Hailstone; 3]) -> Result<(Vec<Rational>, Vec<Rational>), AocError> {
+    let [reference, first, second] = hailstones;
+    let times = [
+        hit_time(reference, second, first)?,
+        hit_time(reference, first, second)?,
+    ];
+    let matrix: Vec<_> = times
+        .iter()
+        .map(|&time| vec![Rational::one(), time])
+        .collect();
+    let axes = [first, second].map(|hailstone| {
+        vector(hailstone.position)
+            .into_iter()
+            .zip(vector(hailstone.velocity))
+            .collect::<Vec<_>>()
+    });
+    // position + time * velocity == hit point, for both hit points and every axis
+    let (positions, velocities) = (0..3)
+        .map(|axis| {
+            let hit_points = (0..2)
+                .map(|idx| {
+                    let (position, velocity) = axes[idx][axis];
+                    Rational::from(position).checked_add(&times[idx].checked_mul(&velocity.into())?)
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            let solution = solve(&matrix, &hit_points)?;
+            Ok((solution[0], solution[1]))
+        })
+        .collect::<Result<Vec<_>, AocError>>()?
+        .into_iter()
+        .unzip();
+    Ok((positions, velocities))
+}
+
+pub fn process(input: &'static str) -> Result<i128, AocError> {
+    let hailstones = parse_hailstones(input)?;
+    if hailstones.len() < 3 {
+        return Err(AocError::MathError(
+            "At least three hailstones are needed to aim the rock".to_string(),
+        ));
+    }
+    // degenerate triples give singular systems, so fall back to the next ones
+    let mut result = Err(AocError::MathError(
+        "All triples of consecutive hailstones are degenerate".to_string(),
+    ));
+    for window in hailstones.windows(3) {
+        result = throw_rock([&window[0], &window[1], &window[2]]);
+        if result.is_ok() {
+            break;
+        }
+    }
+    let (positions, _) = result?;
+    let sum = positions
+        .iter()
+        .try_fold(Rational::zero(), |sum, position| sum.checked_add(position))?;
+    sum.to_integer().ok_or(AocError::MathError(format!(
+        "The rock has to start from integer coordinates, got a sum of {}",
+        sum
+    )))
+}
@@ -0,0 +1,96 @@
+use aoclib::generator::{InputGenerator, Rng};
+
+/// Generates wiring diagrams of two well connected groups of components joined by three wires
+///
+/// Each group is a ring in which every component is also wired to the one after next, which
+/// takes at least four cuts to split, plus some random extra wires
+#[derive(Debug, Clone)]
+pub struct Generator {
+    pub components: usize,
+    /// Extra wires within the groups per component
+    pub extra_wires: f64,
+}
+
+impl Default for Generator {
+    fn default() -> Self {
+        Self {
+            components: 1500,
+            extra_wires: 0.3,
+        }
+    }
+}
+
+const MIN_GROUP_SIZE: usize = 5;
+
+fn component_names(count: usize, rng: &mut Rng) -> Vec<String> {
+    let mut names: Vec<String> = Vec::with_capacity(count);
+    let length = if count > 5000 { 4 } else { 3 };
+    while names.len() < count {
+        let name: String = (0..length)
+            .map(|_| (b'a' + rng.below(26) as u8) as char)
+            .collect();
+        if !names.contains(&name) {
+            names.push(name);
+        }
+    }
+    names
+}
+
+fn group_wires(group: &[usize], extra_wires: f64, rng: &mut Rng) -> Vec<(usize, usize)> {
+    let mut wires: Vec<_> = (0..group.len())
+        .flat_map(|idx| [1, 2].map(|offset| (group[idx], group[(idx + offset) % group.len()])))
+        .collect();
+    let extra = (group.len() as f64 * extra_wires) as usize;
+    (0..extra).for_each(|_| {
+        let (from, to) = (*rng.choose(group), *rng.choose(group));
+        let wired = |&(a, b): &(usize, usize)| (a, b) == (from, to) || (b, a) == (from, to);
+        if from != to && !wires.iter().any(wired) {
+            wires.push((from, to));
+        }
+    });
+    wires
+}
+
+impl InputGenerator for Generator {
+    fn set_size(&mut self, size: usize) {
+        self.components = size;
+    }
+
+    fn generate(&self, rng: &mut Rng) -> String {
+        let components = self.components.max(2 * MIN_GROUP_SIZE);
+        let first_size = rng.between(MIN_GROUP_SIZE, components - MIN_GROUP_SIZE);
+        let mut order: Vec<usize> = (0..components).collect();
+        rng.shuffle(&mut order);
+        let (first, second) = order.split_at(first_size);
+        let mut wires = group_wires(first, self.extra_wires, rng);
+        wires.extend(group_wires(second, self.extra_wires, rng));
+        let group_wire_count = wires.len();
+        while wires.len() < group_wire_count + 3 {
+            let wire = (*rng.choose(first), *rng.choose(second));
+            if !wires.contains(&wire) {
+                wires.push(wire);
+            }
+        }
+        wires.iter_mut().for_each(|wire| {
+            if rng.chance(0.5) {
+                *wire = (wire.1, wire.0);
+            }
+        });
+        rng.shuffle(&mut wires);
+
+        let names = component_names(components, rng);
+        let mut connections: Vec<Vec<usize>> = vec![Vec::new(); components];
+        wires
+            .into_iter()
+            .for_each(|(from, to)| connections[from].push(to));
+        connections
+            .iter()
+            .enumerate()
+            .filter(|(_, others)| !others.is_empty())
+            .map(|(component, others)| {
+                let others: Vec<&str> = others.iter().map(|&other| names[other].as_str()).collect();
+                format!("{}: {}\n", names[component], others.join(" "))
+            })
+            .collect()
+    }
+}
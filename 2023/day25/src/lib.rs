@@ -0,0 +1,72 @@
+pub mod generator;
+pub mod parsing;
+pub mod part1;
+pub mod part2;
+
+#[cfg(test)]
+mod tests {
+    use crate::generator::Generator;
+    use crate::parsing::parse_wiring;
+    use crate::part1;
+    use crate::part2;
+    use aoclib::generator::{check_generated_inputs, InputGenerator};
+    use aoclib::AocError;
+
+    #[test]
+    fn test_part1() -> Result<(), AocError> {
+        let input = include_str!("../test_input1.txt");
+        if input.is_empty() {
+            return Err(AocError::ParseError(
+                "Input file for test 1 is empty".to_string(),
+            ));
+        }
+        let output = part1::process(input)?;
+        let expected_output = 54;
+        assert_eq!(expected_output, output);
+        Ok(())
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = include_str!("../test_input2.txt");
+        let output = part2::process(input);
+        assert!(matches!(output, Err(AocError::LogicError(_))));
+    }
+
+    #[test]
+    fn test_cut_wires() -> Result<(), AocError> {
+        let graph = parse_wiring(include_str!("../test_input1.txt"))?;
+        let cut = graph.min_cut()?;
+        let mut wires: Vec<_> = cut
+            .edges
+            .iter()
+            .map(|&(from, to)| {
+                let mut wire = [*graph.label(from), *graph.label(to)];
+                wire.sort();
+                wire
+            })
+            .collect();
+        wires.sort();
+        assert_eq!(vec![["bvb", "cmg"], ["hfx", "pzl"], ["jqt", "nvd"]], wires);
+        Ok(())
+    }
+
+    #[test]
+    fn test_unsupported_cut() {
+        // a ring falls apart after cutting two wires
+        let output = part1::process("a: b\nb: c\nc: d\nd: a\n");
+        assert!(matches!(output, Err(AocError::UnsupportedInput(_))));
+    }
+
+    #[test]
+    fn test_generated_inputs() -> Result<(), AocError> {
+        check_generated_inputs(&Generator::with_size(40), |input| {
+            let graph = parse_wiring(input)?;
+            let cut = graph.min_cut()?;
+            assert_eq!(3, cut.edges.len());
+            assert_eq!(40, cut.sizes.0 + cut.sizes.1);
+            part1::process(input)?;
+            Ok(())
+        })
+    }
+}
@@ -0,0 +1,31 @@
+use aoclib::graph::Graph;
+use aoclib::AocError;
+use nom::{
+    bytes::complete::tag,
+    character::complete::{alpha1, char as char_parser, line_ending},
+    multi::{many1, separated_list1},
+    sequence::{separated_pair, terminated},
+    IResult,
+};
+
+fn parse_connections(input: &str) -> IResult<&str, (&str, Vec<&str>)> {
+    separated_pair(alpha1, tag(": "), separated_list1(char_parser(' '), alpha1))(input)
+}
+
+/// Parses the wiring diagram into an undirected graph of components
+pub fn parse_wiring(input: &str) -> Result<Graph<&str>, AocError> {
+    let (rest, connections) = many1(terminated(parse_connections, line_ending))(input)?;
+    if !rest.is_empty() {
+        return Err(AocError::ParseError(format!(
+            "Parsing wiring diagram left rest: {}",
+            rest
+        )));
+    }
+    let mut graph = Graph::new_undirected();
+    connections.into_iter().for_each(|(component, others)| {
+        others.into_iter().for_each(|other| {
+            graph.add_edge(component, other);
+        });
+    });
+    Ok(graph)
+}
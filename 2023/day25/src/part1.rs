@@ -0,0 +1,27 @@
+use aoclib::assumptions::Assumptions;
+use aoclib::graph::MinCut;
+use aoclib::AocError;
+
+use crate::parsing::parse_wiring;
+
+const WIRES_TO_CUT: usize = 3;
+
+pub fn assumptions() -> Assumptions<MinCut> {
+    Assumptions::new().assume(
+        "disconnecting the components takes exactly three wires",
+        |cut: &MinCut| {
+            if cut.edges.len() == WIRES_TO_CUT {
+                Ok(())
+            } else {
+                Err(format!("the minimum cut has {} wires", cut.edges.len()))
+            }
+        },
+    )
+}
+
+pub fn process(input: &'static str) -> Result<usize, AocError> {
+    let graph = parse_wiring(input)?;
+    let cut = graph.min_cut()?;
+    assumptions().check(&cut)?;
+    Ok(cut.sizes.0 * cut.sizes.1)
+}
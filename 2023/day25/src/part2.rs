@@ -0,0 +1,8 @@
+use aoclib::AocError;
+
+/// Day 25 only has a single puzzle, the second star is awarded for all the others
+pub fn process(_input: &'static str) -> Result<usize, AocError> {
+    Err(AocError::LogicError(
+        "There is no second part on day 25".to_string(),
+    ))
+}
@@ -0,0 +1,11 @@
+#![no_main]
+
+use aoclib::grid::Grid;
+use aoclib::AocError;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    if let Err(e) = Grid::parse_from(input, day21::part1::parse_tile) {
+        assert!(matches!(e, AocError::ParseError(_)), "{}", e);
+    }
+});
@@ -0,0 +1,13 @@
+#![no_main]
+
+use aoclib::AocError;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    if let Err(e) = day07::part1::parse_hand_infos(input) {
+        assert!(matches!(e, AocError::ParseError(_)), "{}", e);
+    }
+    if let Err(e) = day07::part2::parse_hand_infos(input) {
+        assert!(matches!(e, AocError::ParseError(_)), "{}", e);
+    }
+});
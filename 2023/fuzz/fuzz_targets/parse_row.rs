@@ -0,0 +1,10 @@
+#![no_main]
+
+use aoclib::AocError;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    if let Err(e) = day12::part1::parse_row(input) {
+        assert!(matches!(e, AocError::ParseError(_)), "{}", e);
+    }
+});
@@ -0,0 +1,10 @@
+#![no_main]
+
+use aoclib::AocError;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    if let Err(e) = day19::parsing::parse_input(input) {
+        assert!(matches!(e, AocError::ParseError(_)), "{}", e);
+    }
+});
@@ -0,0 +1,82 @@
+use std::collections::HashMap;
+
+use aoclib::AocError;
+use nom::{
+    branch::alt,
+    bytes::complete::{tag, take_while1},
+    character::complete::{line_ending, not_line_ending, space1},
+    combinator::{eof, map_res, value},
+    multi::many0,
+    sequence::terminated,
+    IResult,
+};
+
+use crate::registry::Part;
+
+/// FNV-1a hash of the input which is stable across runs and platforms
+pub fn input_hash(input: &str) -> u64 {
+    input.bytes().fold(0xcbf29ce484222325, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+    })
+}
+
+pub fn format_hash(hash: u64) -> String {
+    format!("{:016x}", hash)
+}
+
+/// Known answers keyed by the hash of the input they belong to
+#[derive(Debug, Default)]
+pub struct Answers {
+    answers: HashMap<(u64, Part), String>,
+}
+
+impl Answers {
+    pub fn get(&self, input: &str, part: Part) -> Option<&str> {
+        self.answers
+            .get(&(input_hash(input), part))
+            .map(|answer| answer.as_str())
+    }
+
+    pub fn len(&self) -> usize {
+        self.answers.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.answers.is_empty()
+    }
+}
+
+fn parse_hash(input: &str) -> IResult<&str, u64> {
+    map_res(take_while1(|c: char| c.is_ascii_hexdigit()), |hash| {
+        u64::from_str_radix(hash, 16)
+    })(input)
+}
+
+fn parse_part(input: &str) -> IResult<&str, Part> {
+    alt((
+        value(Part::One, tag("part1")),
+        value(Part::Two, tag("part2")),
+    ))(input)
+}
+
+fn parse_answer_line(input: &str) -> IResult<&str, ((u64, Part), String)> {
+    let (input, hash) = terminated(parse_hash, space1)(input)?;
+    let (input, part) = terminated(parse_part, space1)(input)?;
+    let (input, answer) = terminated(not_line_ending, alt((line_ending, eof)))(input)?;
+    Ok((input, ((hash, part), answer.trim().to_string())))
+}
+
+/// Parses an answers file with one `<input hash> <part1|part2> <answer>` entry per line
+pub fn parse_answers(input: &str) -> Result<Answers, AocError> {
+    let (rest, answers) = many0(parse_answer_line)(input)?;
+    if rest.is_empty() {
+        Ok(Answers {
+            answers: answers.into_iter().collect(),
+        })
+    } else {
+        Err(AocError::ParseError(format!(
+            "Parsing answers left rest: {}",
+            rest
+        )))
+    }
+}
@@ -0,0 +1,234 @@
+use std::any::Any;
+use std::fs;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use aoclib::AocError;
+
+use crate::answers::{format_hash, input_hash, Answers};
+use crate::registry::{Part, Solver};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Answer(String),
+    Error(String),
+    Panic(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Unchecked,
+    Correct,
+    Wrong { expected: String },
+}
+
+#[derive(Debug, Clone)]
+pub struct BatchResult {
+    pub file: PathBuf,
+    pub hash: Option<u64>,
+    pub outcome: Outcome,
+    pub time: Duration,
+    pub verdict: Verdict,
+}
+
+/// Returns all files in the directory, sorted by name
+pub fn input_files(dir: &Path) -> Result<Vec<PathBuf>, AocError> {
+    let mut files = fs::read_dir(dir)?
+        .map(|entry| entry.map(|entry| entry.path()))
+        .collect::<Result<Vec<_>, _>>()?;
+    files.retain(|file| file.is_file());
+    files.sort();
+    Ok(files)
+}
+
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown panic".to_string()
+    }
+}
+
+/// Runs the solver on the input and catches errors as well as panics
+pub fn run_solver(solver: Solver, input: &'static str) -> (Outcome, Duration) {
+    let start = Instant::now();
+    let result = panic::catch_unwind(AssertUnwindSafe(|| solver(input)));
+    let time = start.elapsed();
+    let outcome = match result {
+        Ok(Ok(answer)) => Outcome::Answer(answer),
+        Ok(Err(e)) => Outcome::Error(e.to_string()),
+        Err(payload) => Outcome::Panic(panic_message(payload)),
+    };
+    (outcome, time)
+}
+
+/// Result for a file the solver could not be run on
+fn failed_result(file: &Path, outcome: Outcome) -> BatchResult {
+    BatchResult {
+        file: file.to_path_buf(),
+        hash: None,
+        outcome,
+        time: Duration::ZERO,
+        verdict: Verdict::Unchecked,
+    }
+}
+
+fn run_file(solver: Solver, part: Part, file: &Path, answers: Option<&Answers>) -> BatchResult {
+    let input: &'static str = match fs::read_to_string(file) {
+        // the solvers require static inputs, the runner exits after all files are processed
+        Ok(input) => Box::leak(input.into_boxed_str()),
+        Err(e) => return failed_result(file, Outcome::Error(AocError::from(e).to_string())),
+    };
+    let (outcome, time) = run_solver(solver, input);
+    let expected = answers.and_then(|answers| answers.get(input, part));
+    let verdict = match (&outcome, expected) {
+        (_, None) => Verdict::Unchecked,
+        (Outcome::Answer(answer), Some(expected)) if answer == expected => Verdict::Correct,
+        (_, Some(expected)) => Verdict::Wrong {
+            expected: expected.to_string(),
+        },
+    };
+    BatchResult {
+        file: file.to_path_buf(),
+        hash: Some(input_hash(input)),
+        outcome,
+        time,
+        verdict,
+    }
+}
+
+/// Runs the solver on every file in parallel and returns the results in the order of the files
+pub fn run_batch(
+    solver: Solver,
+    part: Part,
+    files: &[PathBuf],
+    answers: Option<&Answers>,
+) -> Vec<BatchResult> {
+    let worker_count = thread::available_parallelism()
+        .map(|n| n.get())
+        .unwrap_or(1)
+        .min(files.len())
+        .max(1);
+    let next_file = AtomicUsize::new(0);
+
+    // panics are reported in the table, so the default hook would only clutter the output
+    let default_hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+    let worker_results: Vec<thread::Result<Vec<(usize, BatchResult)>>> = thread::scope(|scope| {
+        let workers: Vec<_> = (0..worker_count)
+            .map(|_| {
+                scope.spawn(|| {
+                    let mut worker_results = Vec::new();
+                    loop {
+                        let idx = next_file.fetch_add(1, Ordering::Relaxed);
+                        let Some(file) = files.get(idx) else {
+                            break worker_results;
+                        };
+                        // a panic outside of the solver still only affects this file
+                        let result = panic::catch_unwind(AssertUnwindSafe(|| {
+                            run_file(solver, part, file, answers)
+                        }))
+                        .unwrap_or_else(|payload| {
+                            failed_result(file, Outcome::Panic(panic_message(payload)))
+                        });
+                        worker_results.push((idx, result));
+                    }
+                })
+            })
+            .collect();
+        workers.into_iter().map(|worker| worker.join()).collect()
+    });
+    panic::set_hook(default_hook);
+
+    let mut results: Vec<(usize, BatchResult)> = worker_results
+        .into_iter()
+        .flat_map(|worker_result| {
+            worker_result.unwrap_or_else(|payload| panic::resume_unwind(payload))
+        })
+        .collect();
+
+    results.sort_by_key(|(idx, _)| *idx);
+    results.into_iter().map(|(_, result)| result).collect()
+}
+
+fn format_duration(time: Duration) -> String {
+    if time.as_secs() > 0 {
+        format!("{:.2}s", time.as_secs_f64())
+    } else if time.as_millis() > 0 {
+        format!("{:.2}ms", time.as_secs_f64() * 1e3)
+    } else {
+        format!("{:.2}µs", time.as_secs_f64() * 1e6)
+    }
+}
+
+const MAX_STATUS_WIDTH: usize = 100;
+
+/// Shortens errors like nom's, which contain the whole remaining input, to a single table line
+fn truncate_status(status: String) -> String {
+    let first_line = status.lines().next().unwrap_or_default();
+    if first_line.chars().count() > MAX_STATUS_WIDTH {
+        let shortened: String = first_line.chars().take(MAX_STATUS_WIDTH - 3).collect();
+        format!("{}...", shortened)
+    } else {
+        first_line.to_string()
+    }
+}
+
+fn table_row(result: &BatchResult) -> [String; 5] {
+    let file = result
+        .file
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_default();
+    let hash = result.hash.map(format_hash).unwrap_or_default();
+    let (answer, status) = match &result.outcome {
+        Outcome::Answer(answer) => (answer.clone(), "ok".to_string()),
+        Outcome::Error(e) => (String::new(), format!("error: {}", e)),
+        Outcome::Panic(message) => (String::new(), format!("panic: {}", message)),
+    };
+    let status = match &result.verdict {
+        Verdict::Unchecked => status,
+        Verdict::Correct => "correct".to_string(),
+        Verdict::Wrong { expected } if status == "ok" => format!("WRONG, expected {}", expected),
+        Verdict::Wrong { expected } => format!("{}, expected {}", status, expected),
+    };
+    [
+        file,
+        hash,
+        answer,
+        format_duration(result.time),
+        truncate_status(status),
+    ]
+}
+
+pub fn format_table(results: &[BatchResult]) -> String {
+    let header = ["file", "hash", "answer", "time", "status"].map(String::from);
+    let rows: Vec<_> = std::iter::once(header)
+        .chain(results.iter().map(table_row))
+        .collect();
+    let widths: Vec<usize> = (0..5)
+        .map(|col| {
+            rows.iter()
+                .map(|row| row[col].chars().count())
+                .max()
+                .unwrap_or(0)
+        })
+        .collect();
+    rows.iter()
+        .map(|row| {
+            row.iter()
+                .zip(&widths)
+                .map(|(cell, &width)| format!("{:width$}", cell, width = width))
+                .collect::<Vec<_>>()
+                .join(" | ")
+                .trim_end()
+                .to_string()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
@@ -0,0 +1,76 @@
+pub mod answers;
+pub mod batch;
+pub mod registry;
+
+#[cfg(test)]
+mod tests {
+    use crate::answers::{format_hash, input_hash, parse_answers};
+    use crate::batch::{run_solver, Outcome};
+    use crate::registry::{find_solution, Part};
+    use aoclib::AocError;
+
+    #[test]
+    fn test_answers() -> Result<(), AocError> {
+        let input = "some input\n";
+        let answers_file = format!(
+            "{} part1 42\n{} part2 some answer\n",
+            format_hash(input_hash(input)),
+            format_hash(input_hash(input))
+        );
+        let answers = parse_answers(&answers_file)?;
+        assert_eq!(2, answers.len());
+        assert_eq!(Some("42"), answers.get(input, Part::One));
+        assert_eq!(Some("some answer"), answers.get(input, Part::Two));
+        assert_eq!(None, answers.get("other input\n", Part::One));
+        Ok(())
+    }
+
+    #[test]
+    fn test_answers_without_trailing_newline() -> Result<(), AocError> {
+        let input = "some input\n";
+        let answers_file = format!(
+            "{} part1 42\n{} part2 17",
+            format_hash(input_hash(input)),
+            format_hash(input_hash(input))
+        );
+        let answers = parse_answers(&answers_file)?;
+        assert_eq!(2, answers.len());
+        assert_eq!(Some("17"), answers.get(input, Part::Two));
+        Ok(())
+    }
+
+    #[test]
+    fn test_run_solver() -> Result<(), AocError> {
+        let solution = find_solution("8")?;
+        let input = include_str!("../../day08/test_input1b.txt");
+        let (outcome, _) = run_solver(solution.solver(Part::One), input);
+        assert_eq!(Outcome::Answer("6".to_string()), outcome);
+        let (outcome, _) = run_solver(solution.solver(Part::One), "not a network\n");
+        assert!(matches!(outcome, Outcome::Error(_)));
+        Ok(())
+    }
+
+    #[test]
+    fn test_find_solution() -> Result<(), AocError> {
+        assert_eq!("2023/day07", find_solution("day07")?.name());
+        let solution = find_solution("2022/3")?;
+        assert_eq!("2022/day03", solution.name());
+        assert!(solution.generate.is_none());
+        let input = include_str!("../../../2022/day03/test_input1.txt");
+        let (outcome, _) = run_solver(solution.solver(Part::One), input);
+        assert_eq!(Outcome::Answer("157".to_string()), outcome);
+        assert!(find_solution("2021/1").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_text_answer() -> Result<(), AocError> {
+        let solution = find_solution("2022/5")?;
+        let input = include_str!("../../../2022/day05/test_input1.txt");
+        let (outcome, _) = run_solver(solution.solver(Part::Two), input);
+        assert_eq!(Outcome::Answer("MCD".to_string()), outcome);
+        let answers = parse_answers(&format!("{} part2 MCD\n", format_hash(input_hash(input))))?;
+        assert_eq!(Some("MCD"), answers.get(input, Part::Two));
+        Ok(())
+    }
+}
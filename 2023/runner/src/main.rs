@@ -0,0 +1,153 @@
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+
+use aoclib::AocError;
+
+use runner::answers::parse_answers;
+use runner::batch::{format_table, input_files, run_batch, run_solver, Outcome};
+use runner::registry::{find_solution, solutions, Part, Solution};
+
+const USAGE: &str = "Usage:
+    runner                                               run every day on its own input
+    runner <day> [part]                                  run one day on its own input
+                                                         (<day> is e.g. 7, day07 or 2022/7)
+    runner <day> <part> --dir <dir> [--answers <file>]   run one day on every input in <dir>
+    runner <day> --generate <seed> [size]                print a generated input for the day";
+
+const DEFAULT_GENERATED_SIZE: usize = 20;
+
+enum Mode {
+    All,
+    Single(Solution, Vec<Part>),
+    Batch {
+        solution: Solution,
+        part: Part,
+        dir: PathBuf,
+        answers_file: Option<PathBuf>,
+    },
+    Generate {
+        solution: Solution,
+        seed: u64,
+        size: usize,
+    },
+}
+
+fn parse_number<T: std::str::FromStr>(number: &str) -> Result<T, AocError> {
+    number
+        .parse()
+        .map_err(|_| AocError::ParseError(format!("Invalid number: {}", number)))
+}
+
+fn parse_args(args: &[String]) -> Result<Mode, AocError> {
+    match args {
+        [] => Ok(Mode::All),
+        [day] => Ok(Mode::Single(
+            find_solution(day)?,
+            vec![Part::One, Part::Two],
+        )),
+        [day, generate_flag, seed, rest @ ..] if generate_flag == "--generate" => {
+            let size = match rest {
+                [] => DEFAULT_GENERATED_SIZE,
+                [size] => parse_number(size)?,
+                _ => {
+                    return Err(AocError::ParseError(format!(
+                        "Invalid arguments\n{}",
+                        USAGE
+                    )))
+                }
+            };
+            Ok(Mode::Generate {
+                solution: find_solution(day)?,
+                seed: parse_number(seed)?,
+                size,
+            })
+        }
+        [day, part] => Ok(Mode::Single(find_solution(day)?, vec![Part::parse(part)?])),
+        [day, part, dir_flag, dir, rest @ ..] if dir_flag == "--dir" => {
+            let answers_file = match rest {
+                [] => None,
+                [answers_flag, file] if answers_flag == "--answers" => Some(PathBuf::from(file)),
+                _ => {
+                    return Err(AocError::ParseError(format!(
+                        "Invalid arguments\n{}",
+                        USAGE
+                    )))
+                }
+            };
+            Ok(Mode::Batch {
+                solution: find_solution(day)?,
+                part: Part::parse(part)?,
+                dir: PathBuf::from(dir),
+                answers_file,
+            })
+        }
+        _ => Err(AocError::ParseError(format!(
+            "Invalid arguments\n{}",
+            USAGE
+        ))),
+    }
+}
+
+fn run_on_own_input(solution: &Solution, parts: &[Part]) -> Result<(), AocError> {
+    let input = fs::read_to_string(solution.default_input_path())?;
+    let input: &'static str = Box::leak(input.into_boxed_str());
+    parts.iter().for_each(|&part| {
+        let (outcome, time) = run_solver(solution.solver(part), input);
+        let result = match outcome {
+            Outcome::Answer(answer) => answer,
+            Outcome::Error(e) => format!("error: {}", e),
+            Outcome::Panic(message) => format!("panic: {}", message),
+        };
+        println!(
+            "{} {}: {} ({:?})",
+            solution.name(),
+            part.name(),
+            result,
+            time
+        );
+    });
+    Ok(())
+}
+
+fn main() -> Result<(), AocError> {
+    let args: Vec<String> = env::args().skip(1).collect();
+    match parse_args(&args)? {
+        Mode::All => solutions()
+            .iter()
+            .try_for_each(|solution| run_on_own_input(solution, &[Part::One, Part::Two])),
+        Mode::Single(solution, parts) => run_on_own_input(&solution, &parts),
+        Mode::Batch {
+            solution,
+            part,
+            dir,
+            answers_file,
+        } => {
+            let answers = answers_file
+                .map(|file| parse_answers(&fs::read_to_string(file)?))
+                .transpose()?;
+            let files = input_files(&dir)?;
+            let results = run_batch(solution.solver(part), part, &files, answers.as_ref());
+            println!(
+                "=== {} {} on {} inputs ===",
+                solution.name(),
+                part.name(),
+                files.len()
+            );
+            println!("{}", format_table(&results));
+            Ok(())
+        }
+        Mode::Generate {
+            solution,
+            seed,
+            size,
+        } => {
+            let generate = solution.generate.ok_or(AocError::LogicError(format!(
+                "No input generator for {}",
+                solution.name()
+            )))?;
+            print!("{}", generate(seed, size));
+            Ok(())
+        }
+    }
+}
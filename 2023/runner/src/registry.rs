@@ -0,0 +1,148 @@
+use std::path::PathBuf;
+
+use aoclib::generator::InputGenerator;
+use aoclib::AocError;
+
+pub type Solver = fn(&'static str) -> Result<String, AocError>;
+/// Generates an input of the given size from the seed
+pub type Generate = fn(u64, usize) -> String;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Part::One => "part1",
+            Part::Two => "part2",
+        }
+    }
+
+    pub fn parse(part: &str) -> Result<Self, AocError> {
+        match part {
+            "1" | "part1" => Ok(Part::One),
+            "2" | "part2" => Ok(Part::Two),
+            _ => Err(AocError::ParseError(format!("Unknown part: {}", part))),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Solution {
+    pub year: u32,
+    pub day: &'static str,
+    part1: Solver,
+    part2: Solver,
+    pub generate: Option<Generate>,
+}
+
+impl Solution {
+    pub fn solver(&self, part: Part) -> Solver {
+        match part {
+            Part::One => self.part1,
+            Part::Two => self.part2,
+        }
+    }
+
+    /// Name of the day including its year, e.g. `2023/day07`
+    pub fn name(&self) -> String {
+        format!("{}/{}", self.year, self.day)
+    }
+
+    /// Location of the puzzle input that is checked in next to the day's crate
+    pub fn default_input_path(&self) -> PathBuf {
+        let year = self.year.to_string();
+        [
+            env!("CARGO_MANIFEST_DIR"),
+            "..",
+            "..",
+            &year,
+            self.day,
+            "input.txt",
+        ]
+        .iter()
+        .collect()
+    }
+}
+
+const DEFAULT_YEAR: u32 = 2023;
+
+macro_rules! solution {
+    ($day:ident) => {
+        Solution {
+            generate: Some(|seed, size| {
+                $day::generator::Generator::with_size(size).generate_with_seed(seed)
+            }),
+            ..solution!(DEFAULT_YEAR, $day, $day)
+        }
+    };
+    // days of other years live in crates named after the year and come without generators
+    ($year:expr, $day:ident, $krate:ident) => {
+        Solution {
+            year: $year,
+            day: stringify!($day),
+            part1: |input| $krate::part1::process(input).map(|answer| answer.to_string()),
+            part2: |input| $krate::part2::process(input).map(|answer| answer.to_string()),
+            generate: None,
+        }
+    };
+}
+
+pub fn solutions() -> Vec<Solution> {
+    vec![
+        solution!(2022, day01, aoc2022_day01),
+        solution!(2022, day02, aoc2022_day02),
+        solution!(2022, day03, aoc2022_day03),
+        solution!(2022, day04, aoc2022_day04),
+        solution!(2022, day05, aoc2022_day05),
+        solution!(day01),
+        solution!(day02),
+        solution!(day03),
+        solution!(day04),
+        solution!(day05),
+        solution!(day06),
+        solution!(day07),
+        solution!(day08),
+        solution!(day09),
+        solution!(day10),
+        solution!(day11),
+        solution!(day12),
+        solution!(day13),
+        solution!(day14),
+        solution!(day15),
+        solution!(day16),
+        solution!(day17),
+        solution!(day18),
+        solution!(day19),
+        solution!(day20),
+        solution!(day21),
+        solution!(day22),
+        solution!(day23),
+        solution!(day24),
+        solution!(day25),
+    ]
+}
+
+/// Finds the solution for a day given either as its crate name (`day07`) or its number (`7`),
+/// optionally prefixed with the year (`2022/7`), which defaults to 2023
+pub fn find_solution(day: &str) -> Result<Solution, AocError> {
+    let (year, day_in_year) = match day.split_once('/') {
+        Some((year, day_in_year)) => (
+            year.parse::<u32>()
+                .map_err(|_| AocError::ParseError(format!("Invalid year: {}", year)))?,
+            day_in_year,
+        ),
+        None => (DEFAULT_YEAR, day),
+    };
+    let day_name = match day_in_year.parse::<u32>() {
+        Ok(day_num) => format!("day{:02}", day_num),
+        Err(_) => day_in_year.to_string(),
+    };
+    solutions()
+        .into_iter()
+        .find(|solution| solution.year == year && solution.day == day_name)
+        .ok_or(AocError::LogicError(format!("No solution for {}", day)))
+}